@@ -1,7 +1,7 @@
 use std::{ffi::CStr, mem, os::raw::c_char};
 
 use mahjong_core::{
-    mahjong_generated::open_mahjong::{ActionType, GameStateT, PaiT, Player},
+    mahjong_generated::open_mahjong::{ActionType, GameStateT, PaiT, PlayerView},
     play_log::PlayLog,
     shanten::PaiState,
 };
@@ -12,8 +12,8 @@ pub extern "C" fn get_work_mem_size() -> usize {
 }
 
 #[no_mangle]
-pub extern "C" fn get_player_mem_size() -> usize {
-    mem::size_of::<Player>()
+pub extern "C" fn get_player_view_mem_size() -> usize {
+    mem::size_of::<PlayerView>()
 }
 
 #[no_mangle]
@@ -25,48 +25,142 @@ pub unsafe extern "C" fn initialize(ptr: *mut GameStateT, title: *const c_char,
 
     gamestate.clone_from(&GameStateT::default());
     gamestate.create(title_slice, player_len, &mut play_log);
+    gamestate.shuffle();
     gamestate.start(&mut play_log);
 }
 
+/// シードを指定して初期化する
+/// 同じシードなら席順と牌山が再現される
 #[no_mangle]
-pub unsafe extern "C" fn get_player_shanten(ptr: *mut GameStateT, player_index: usize) -> i32 {
+pub unsafe extern "C" fn initialize_with_seed(
+    ptr: *mut GameStateT,
+    title: *const c_char,
+    player_len: u32,
+    seed: u64,
+) {
     let gamestate = ptr.as_mut().unwrap();
+    let title_str = CStr::from_ptr(title);
+    let title_slice = title_str.to_bytes();
+    let mut play_log = PlayLog::new();
 
-    let player = gamestate.get_player(player_index);
-    let mut tehai: Vec<PaiT> = player.tehai[0..(player.tehai_len as usize)]
-        .iter()
-        .cloned()
-        .collect();
+    gamestate.clone_from(&GameStateT::default());
+    gamestate.create_with_seed(title_slice, player_len, seed, &mut play_log);
+    gamestate.shuffle();
+    gamestate.start(&mut play_log);
+}
 
-    if player.is_tsumo {
-        tehai.push(player.tsumohai)
+/// 自分の手牌からシャンテン数を計算してptr_shantenに書き込む
+/// 他家の手牌を参照しないよう、get_player_viewと同じ情報だけを使う
+/// シャンテン数は-1にもなるので、戻り値は成功なら0、失敗ならGameErrorのエラーコードとする
+///
+/// # Safety
+/// ptrは初期化済みのGameStateTを、ptr_shantenは書き込み可能なi32を指していること
+#[no_mangle]
+pub unsafe extern "C" fn get_player_shanten(
+    ptr: *mut GameStateT,
+    player_index: usize,
+    ptr_shanten: *mut i32,
+) -> i32 {
+    let gamestate = ptr.as_mut().unwrap();
+
+    let view = match gamestate.get_player_view(player_index) {
+        Ok(view) => view,
+        Err(e) => return e.code(),
+    };
+    let mut tehai: Vec<PaiT> = view.tehai[0..(view.tehai_len as usize)].to_vec();
+
+    if view.is_tsumo {
+        tehai.push(view.tsumohai)
     }
 
-    PaiState::from(&tehai).get_shanten(0)
+    *ptr_shanten =
+        PaiState::from(&tehai).get_shanten(view.players[player_index].mentsu_len as usize);
+    0
 }
 
+/// 成功なら0、失敗ならGameErrorのエラーコードを返す
 #[no_mangle]
 pub unsafe extern "C" fn do_action(
     ptr: *mut GameStateT,
     action_type: u32,
     player_index: usize,
     param: u32,
-) {
+) -> i32 {
     let gamestate = ptr.as_mut().unwrap();
     let mut play_log = PlayLog::new();
 
-    let _ = gamestate.action(&mut play_log, ActionType(action_type), player_index, param);
+    match gamestate.action(&mut play_log, ActionType(action_type), player_index, param) {
+        Ok(()) => 0,
+        Err(e) => e.code(),
+    }
 }
 
+/// 捨て牌に対する応答を送る
+/// 成功なら0、失敗ならGameErrorのエラーコードを返す
+///
+/// # Safety
+/// ptrは初期化済みのGameStateTを指していること
 #[no_mangle]
-pub unsafe extern "C" fn get_player_state(
+pub unsafe extern "C" fn respond(
     ptr: *mut GameStateT,
+    action_type: u32,
     player_index: usize,
-    ptr_player: *mut Player,
-) {
+    param: u32,
+) -> i32 {
     let gamestate = ptr.as_mut().unwrap();
+    let mut play_log = PlayLog::new();
+
+    match gamestate.respond(&mut play_log, player_index, ActionType(action_type), param) {
+        Ok(()) => 0,
+        Err(e) => e.code(),
+    }
+}
+
+/// 局を進め、続行する場合は次の局を配牌する
+/// 終局したかどうかをptr_is_game_endに書き込む
+/// 成功なら0、失敗ならGameErrorのエラーコードを返す
+///
+/// # Safety
+/// ptrは初期化済みのGameStateTを、ptr_is_game_endは書き込み可能なboolを指していること
+#[no_mangle]
+pub unsafe extern "C" fn next_kyoku(ptr: *mut GameStateT, ptr_is_game_end: *mut bool) -> i32 {
+    let gamestate = ptr.as_mut().unwrap();
+    let mut play_log = PlayLog::new();
 
-    let player = gamestate.get_player(player_index);
+    if let Err(e) = gamestate.next_kyoku(&mut play_log) {
+        return e.code();
+    }
 
-    *ptr_player = player.pack();
+    if !gamestate.is_game_end {
+        // サーバーシードがコミット済みなら検証可能な牌山を作る
+        if gamestate.is_seed_committed {
+            if let Err(e) = gamestate.shuffle_fair() {
+                return e.code();
+            }
+        } else {
+            gamestate.shuffle();
+        }
+        gamestate.start(&mut play_log);
+    }
+
+    *ptr_is_game_end = gamestate.is_game_end;
+    0
+}
+
+/// 成功なら0、失敗ならGameErrorのエラーコードを返す
+#[no_mangle]
+pub unsafe extern "C" fn get_player_view(
+    ptr: *mut GameStateT,
+    player_index: usize,
+    ptr_view: *mut PlayerView,
+) -> i32 {
+    let gamestate = ptr.as_mut().unwrap();
+
+    match gamestate.get_player_view(player_index) {
+        Ok(view) => {
+            *ptr_view = view.pack();
+            0
+        }
+        Err(e) => e.code(),
+    }
 }
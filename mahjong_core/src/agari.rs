@@ -1,8 +1,6 @@
 use anyhow::bail;
 
-use crate::mahjong_generated::open_mahjong::{
-    GameStateT, Mentsu, MentsuFlag, MentsuType, Pai, PlayerT,
-};
+use crate::mahjong_generated::open_mahjong::{GameStateT, Mentsu, MentsuFlag, MentsuType, Pai};
 
 #[derive(Default, Debug)]
 pub struct Shuntsu {
@@ -42,6 +40,15 @@ pub struct AgariState {
     pub zikaze: u32,
 }
 
+/// 和了の評価方法
+/// 流局時のテンパイ判定はツモとして評価するが、海底や嶺上などの状態役は含めない
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AgariType {
+    Tsumo,
+    Ron,
+    Tenpai,
+}
+
 #[derive(Default, Debug)]
 pub struct Agari {
     pub score: i32,
@@ -90,7 +97,13 @@ fn is_penchan(mentsu: &Mentsu) -> bool {
 }
 
 pub trait AgariBehavior {
-    fn get_agari(&self, who: usize, mentsu: &Vec<Mentsu>, fulo: &Vec<Mentsu>) -> AgariState;
+    fn get_agari(
+        &self,
+        who: usize,
+        mentsu: &Vec<Mentsu>,
+        fulo: &Vec<Mentsu>,
+        tsumo: bool,
+    ) -> AgariState;
     fn get_condition_yaku(&self, who: usize, state: &AgariState) -> Vec<(String, i32)>;
     fn get_dora_yaku(
         &self,
@@ -98,6 +111,7 @@ pub trait AgariBehavior {
         mentsu: &Vec<Mentsu>,
         fulo: &Vec<Mentsu>,
         nukidora: usize,
+        akadora: usize,
     ) -> Vec<(String, i32)>;
     fn get_best_agari(
         &self,
@@ -105,6 +119,8 @@ pub trait AgariBehavior {
         mentsu: &Vec<Vec<Mentsu>>,
         fulo: &Vec<Mentsu>,
         nukidora: usize,
+        akadora: usize,
+        agari_type: AgariType,
     ) -> anyhow::Result<Agari>;
 }
 
@@ -151,7 +167,12 @@ pub fn add_machi_to_mentsu(mentsu: &Vec<Vec<Mentsu>>, p: &Pai) -> Vec<Vec<Mentsu
     result
 }
 
-fn dora_pai_num(pai_num: u8) -> u8 {
+fn dora_pai_num(pai_num: u8, is_sanma: bool) -> u8 {
+    // 三麻は2m〜8mがないので1mの次は9m
+    if is_sanma && pai_num == 0 {
+        return 8;
+    }
+
     // 北
     if pai_num == 30 {
         return 27;
@@ -170,28 +191,96 @@ fn dora_pai_num(pai_num: u8) -> u8 {
 }
 
 // 状態役
-fn is_riichi(_state: &AgariState, player: &PlayerT) -> Option<(String, i32)> {
-    if player.is_riichi {
+fn is_riichi(game: &GameStateT, who: usize, _state: &AgariState) -> Option<(String, i32)> {
+    let player = &game.players[who];
+
+    if player.is_double_riichi {
+        Some(("ダブル立直".to_string(), 2))
+    } else if player.is_riichi {
         Some(("立直".to_string(), 1))
     } else {
         None
     }
 }
 
-fn is_ippatsu(_state: &AgariState, player: &PlayerT) -> Option<(String, i32)> {
-    if player.is_ippatsu {
+fn is_ippatsu(game: &GameStateT, who: usize, _state: &AgariState) -> Option<(String, i32)> {
+    if game.players[who].is_ippatsu {
         Some(("一発".to_string(), 1))
     } else {
         None
     }
 }
 
+fn is_haitei(game: &GameStateT, who: usize, state: &AgariState) -> Option<(String, i32)> {
+    if state.tsumo && !game.players[who].is_rinshan && game.is_last_pai() {
+        Some(("海底摸月".to_string(), 1))
+    } else {
+        None
+    }
+}
+
+fn is_houtei(game: &GameStateT, _who: usize, state: &AgariState) -> Option<(String, i32)> {
+    if !state.tsumo && !game.is_chankan && game.is_last_pai() {
+        Some(("河底撈魚".to_string(), 1))
+    } else {
+        None
+    }
+}
+
+fn is_rinshan(game: &GameStateT, who: usize, state: &AgariState) -> Option<(String, i32)> {
+    if state.tsumo && game.players[who].is_rinshan {
+        Some(("嶺上開花".to_string(), 1))
+    } else {
+        None
+    }
+}
+
+fn is_chankan(game: &GameStateT, _who: usize, state: &AgariState) -> Option<(String, i32)> {
+    if !state.tsumo && game.is_chankan {
+        Some(("搶槓".to_string(), 1))
+    } else {
+        None
+    }
+}
+
+fn is_tenhou(game: &GameStateT, who: usize, state: &AgariState) -> Option<(String, i32)> {
+    // 最初のツモ番でツモ牌を持っている場合のみ
+    if !state.tsumo || !game.players[who].is_tsumo || !game.is_first_turn(who) {
+        return None;
+    }
+
+    if who == game.oya as usize {
+        Some(("天和".to_string(), -1))
+    } else {
+        Some(("地和".to_string(), -1))
+    }
+}
+
+fn is_renhou(game: &GameStateT, who: usize, state: &AgariState) -> Option<(String, i32)> {
+    if state.tsumo || !game.is_first_turn(who) {
+        return None;
+    }
+
+    // renhou_type 0: なし, 1: 役満, 2: 満貫
+    match game.rule.renhou_type {
+        1 => Some(("人和".to_string(), -1)),
+        2 => Some(("人和".to_string(), 5)),
+        _ => None,
+    }
+}
+
 impl AgariBehavior for GameStateT {
-    fn get_agari(&self, who: usize, mentsu: &Vec<Mentsu>, fulo: &Vec<Mentsu>) -> AgariState {
+    fn get_agari(
+        &self,
+        who: usize,
+        mentsu: &Vec<Mentsu>,
+        fulo: &Vec<Mentsu>,
+        tsumo: bool,
+    ) -> AgariState {
         let mut agari = AgariState {
             fu: 20,
             menzen: true,
-            tsumo: true,
+            tsumo,
             shuntsu: Default::default(),
             koutsu: Default::default(),
             toitsu: Default::default(),
@@ -210,7 +299,10 @@ impl AgariBehavior for GameStateT {
             zikaze: self.get_zikaze(who),
         };
 
-        if fulo.len() > 0 {
+        if fulo
+            .iter()
+            .any(|m| m.mentsu_type() != MentsuType::TYPE_ANKAN)
+        {
             agari.menzen = false;
         }
 
@@ -250,9 +342,13 @@ impl AgariBehavior for GameStateT {
                     }
                 }
                 MentsuType::TYPE_KOUTSU => {
+                    // ロンで完成した刻子は明刻扱い
+                    let is_minkou = !agari.tsumo && is_tanki(item);
                     agari.n_koutsu += 1;
-                    agari.n_ankou += 1;
-                    let mut fu = 4;
+                    if !is_minkou {
+                        agari.n_ankou += 1;
+                    }
+                    let mut fu = if is_minkou { 2 } else { 4 };
                     let num = item.pai_list().get(0).pai_num();
 
                     if num >= 27 {
@@ -490,11 +586,14 @@ impl AgariBehavior for GameStateT {
     }
 
     fn get_condition_yaku(&self, who: usize, state: &AgariState) -> Vec<(String, i32)> {
-        let check_list = [is_riichi, is_ippatsu];
+        let check_list = [
+            is_riichi, is_ippatsu, is_haitei, is_houtei, is_rinshan, is_chankan, is_tenhou,
+            is_renhou,
+        ];
 
         check_list
             .iter()
-            .flat_map(|f| f(state, &self.players[who]))
+            .flat_map(|f| f(self, who, state))
             .collect()
     }
 
@@ -504,11 +603,13 @@ impl AgariBehavior for GameStateT {
         mentsu: &Vec<Mentsu>,
         fulo: &Vec<Mentsu>,
         nukidora: usize,
+        akadora: usize,
     ) -> Vec<(String, i32)> {
         let mut ret = Vec::new();
         let dora_pais = self.get_dora();
         let uradora_pais = self.get_uradora();
         let player = &self.players[who];
+        let is_sanma = self.is_sanma();
 
         let dora_num = mentsu
             .iter()
@@ -517,7 +618,7 @@ impl AgariBehavior for GameStateT {
                 m.pai_list().iter().take(m.pai_len() as usize).map(|p| {
                     dora_pais
                         .iter()
-                        .filter(|d| dora_pai_num(d.pai_num) == p.pai_num())
+                        .filter(|d| dora_pai_num(d.pai_num, is_sanma) == p.pai_num())
                         .count()
                 })
             })
@@ -532,7 +633,7 @@ impl AgariBehavior for GameStateT {
                     m.pai_list().iter().take(m.pai_len() as usize).map(|p| {
                         uradora_pais
                             .iter()
-                            .filter(|d| dora_pai_num(d.pai_num) == p.pai_num())
+                            .filter(|d| dora_pai_num(d.pai_num, is_sanma) == p.pai_num())
                             .count()
                     })
                 })
@@ -548,6 +649,10 @@ impl AgariBehavior for GameStateT {
         if uradora_num > 0 {
             ret.push(("裏ドラ".to_string(), uradora_num as i32));
         }
+
+        if akadora > 0 {
+            ret.push(("赤ドラ".to_string(), akadora as i32));
+        }
         ret
     }
 
@@ -557,14 +662,35 @@ impl AgariBehavior for GameStateT {
         mentsu: &Vec<Vec<Mentsu>>,
         fulo: &Vec<Mentsu>,
         nukidora: usize,
+        akadora: usize,
+        agari_type: AgariType,
     ) -> anyhow::Result<Agari> {
+        let tsumo = agari_type != AgariType::Ron;
         let ret = mentsu
             .iter()
             .map(|m| {
-                let agari = self.get_agari(who, m, fulo);
-                let mut yakus = self.get_condition_yaku(who, &agari);
+                let agari = self.get_agari(who, m, fulo, tsumo);
+                let mut yakus = match agari_type {
+                    AgariType::Tenpai => Vec::new(),
+                    _ => self.get_condition_yaku(who, &agari),
+                };
                 yakus.extend(agari.get_yaku_list());
-                yakus.extend(self.get_dora_yaku(who, m, fulo, nukidora));
+                // 喰いタンなし
+                if !self.rule.enable_kuitan && !agari.menzen {
+                    yakus.retain(|(name, _)| name != "断么九");
+                }
+                // 5本場以上は二飜縛り (役満は除く)
+                if self.rule.enable_ryanhan_shibari
+                    && self.tsumobou >= 5
+                    && yakus.iter().all(|(_, han)| *han > 0)
+                    && yakus.iter().map(|(_, han)| han).sum::<i32>() < 2
+                {
+                    yakus.clear();
+                }
+                // ドラだけでは和了できない
+                if !yakus.is_empty() {
+                    yakus.extend(self.get_dora_yaku(who, m, fulo, nukidora, akadora));
+                }
                 agari.get_agari(&yakus)
             })
             .max_by_key(|x| x.score);
@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// 対局処理のエラー
+/// 数値はFFI経由で返すエラーコードなので変更しないこと
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    NotYourTurn = 1,
+    NotTenpai = 2,
+    AlreadyRiichi = 3,
+    NotMenzen = 4,
+    InvalidTileIndex = 5,
+    Furiten = 6,
+    NoYaku = 7,
+    WallExhausted = 8,
+    WallNotExhausted = 9,
+    NotAgari = 10,
+    KyokuEnded = 11,
+    KyokuNotEnded = 12,
+    GameEnded = 13,
+    InvalidState = 14,
+    NoSutehai = 15,
+    OwnSutehai = 16,
+    InRiichi = 17,
+    NakiLimit = 18,
+    KanLimit = 19,
+    InvalidMentsu = 20,
+    NotKyuushu = 21,
+    IllegalAction = 22,
+    AlreadyResponded = 23,
+    InvalidPlayerIndex = 24,
+    PlayerFull = 25,
+    UnknownAction = 26,
+    InvalidPlayerName = 27,
+    NoShuffleSeed = 28,
+    Kuikae = 29,
+    NotEnoughScore = 30,
+    SeedNotCommitted = 31,
+}
+
+impl GameError {
+    /// FFIで返すエラーコード
+    pub fn code(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            GameError::NotYourTurn => "手番ではありません",
+            GameError::NotTenpai => "テンパイではありません",
+            GameError::AlreadyRiichi => "すでにリーチしています",
+            GameError::NotMenzen => "面前ではありません",
+            GameError::InvalidTileIndex => "手牌のインデックスが不正です",
+            GameError::Furiten => "フリテンです",
+            GameError::NoYaku => "役がありません",
+            GameError::WallExhausted => "牌山が残っていません",
+            GameError::WallNotExhausted => "牌山が残っています",
+            GameError::NotAgari => "和了ではありません",
+            GameError::KyokuEnded => "局が終了しています",
+            GameError::KyokuNotEnded => "局が終了していません",
+            GameError::GameEnded => "対局が終了しています",
+            GameError::InvalidState => "この状態では実行できません",
+            GameError::NoSutehai => "対象の捨て牌がありません",
+            GameError::OwnSutehai => "自分の捨て牌です",
+            GameError::InRiichi => "リーチ後はできません",
+            GameError::NakiLimit => "これ以上鳴けません",
+            GameError::KanLimit => "これ以上槓できません",
+            GameError::InvalidMentsu => "面子になりません",
+            GameError::NotKyuushu => "九種九牌ではありません",
+            GameError::IllegalAction => "選択できない行動です",
+            GameError::AlreadyResponded => "すでに応答しています",
+            GameError::InvalidPlayerIndex => "プレイヤーのインデックスが不正です",
+            GameError::PlayerFull => "プレイヤーが埋まっています",
+            GameError::UnknownAction => "未対応の行動です",
+            GameError::InvalidPlayerName => "プレイヤー名が不正です",
+            GameError::NoShuffleSeed => "公開できるシードがありません",
+            GameError::Kuikae => "喰い替えになる牌は捨てられません",
+            GameError::NotEnoughScore => "点数が足りません",
+            GameError::SeedNotCommitted => "サーバーシードがコミットされていません",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// 条件を満たさなければGameErrorを返す
+macro_rules! ensure {
+    ($cond:expr, $err:expr) => {
+        if !$cond {
+            return Err($err);
+        }
+    };
+}
+
+pub(crate) use ensure;
+
+pub type Result<T> = std::result::Result<T, GameError>;
@@ -0,0 +1,76 @@
+use crate::{fbs_utils::TakuControl, mahjong_generated::open_mahjong::TakuT};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+
+/// 牌山を再現するためのシード一式
+/// 局の終了時に公開され、誰でも牌山を検証できる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleSeed {
+    pub game_id: [u8; 16],
+    pub kyoku_index: u32,
+    pub server_seed: [u8; 32],
+    pub client_seeds: Vec<u64>,
+    pub is_sanma: bool,
+}
+
+impl ShuffleSeed {
+    /// サーバーシードとクライアントシードを混ぜて牌山用のシードを作る
+    fn get_wall_seed(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.server_seed);
+        hasher.update(self.game_id);
+        hasher.update(self.kyoku_index.to_le_bytes());
+        for seed in &self.client_seeds {
+            hasher.update(seed.to_le_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    pub fn create_taku(&self) -> TakuT {
+        let mut rng = ChaCha20Rng::from_seed(self.get_wall_seed());
+
+        if self.is_sanma {
+            TakuT::create_sanma_shuffled_with(&mut rng)
+        } else {
+            TakuT::create_shuffled_with(&mut rng)
+        }
+    }
+}
+
+/// クライアントシードを受け付ける前に公開するサーバーシードのコミットメント
+pub fn get_server_seed_hash(server_seed: &[u8; 32]) -> [u8; 32] {
+    Sha256::digest(server_seed).into()
+}
+
+/// 局の開始時に公開する牌山のコミットメント
+/// サーバーシードを含めるので、公開前に牌山を総当たりで求めることはできない
+pub fn get_wall_hash(taku: &TakuT, server_seed: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(server_seed);
+    for i in 0..taku.length as usize {
+        let pai = taku.get(i).unwrap();
+        hasher.update([pai.pai_num, pai.id]);
+    }
+    hasher.finalize().into()
+}
+
+/// 公開されたシードから牌山を作り直し、コミットメントと一致すればその牌山を返す
+/// サーバーシードがクライアントシードより先にコミットされたものかも確かめる
+pub fn verify(
+    seed: &ShuffleSeed,
+    server_seed_hash: &[u8; 32],
+    wall_hash: &[u8; 32],
+) -> Option<TakuT> {
+    if get_server_seed_hash(&seed.server_seed) != *server_seed_hash {
+        return None;
+    }
+
+    let taku = seed.create_taku();
+
+    if get_wall_hash(&taku, &seed.server_seed) == *wall_hash {
+        Some(taku)
+    } else {
+        None
+    }
+}
@@ -1,10 +1,11 @@
 use std::{fmt::Display, ops::Range};
 
 use crate::mahjong_generated::open_mahjong::{
-    FixedString, FixedStringT, Pai, PaiT, PlayerT, Taku, TakuT,
+    FixedString, FixedStringT, MentsuType, Pai, PaiT, PlayerT, Taku, TakuT,
 };
 use anyhow::{bail, ensure};
-use rand::prelude::SliceRandom;
+use rand::{prelude::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 
 // 牌の表示
 impl Display for PaiT {
@@ -15,10 +16,23 @@ impl Display for PaiT {
 
         let suit = (num / 9) as usize;
 
-        write!(f, "{}{}", colors[suit], (num % 9) + 1)
+        // 赤5は0で表す
+        if self.is_aka {
+            write!(f, "{}0", colors[suit])
+        } else {
+            write!(f, "{}{}", colors[suit], (num % 9) + 1)
+        }
     }
 }
 
+/// 赤牌かどうか
+/// aka_typeは萬子・筒子・索子の順に2bitずつ赤5の枚数を表し、id (1〜4) がその枚数以下の5が赤牌になる
+pub fn is_aka(pai_num: u8, id: u8, aka_type: u32) -> bool {
+    let suit = pai_num / 9;
+
+    suit < 3 && pai_num % 9 == 4 && (1..=aka_type >> (suit * 2) & 0b11).contains(&(id as u32))
+}
+
 // 牌の並び替え
 impl PartialOrd for PaiT {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
@@ -38,8 +52,14 @@ impl PartialOrd for PaiT {
 impl Eq for PaiT {}
 
 impl PaiT {
+    /// 0〜135の牌番号 (idは1〜4)
     pub fn get_pai_id(&self) -> u32 {
-        (self.pai_num as u32) * 4 + self.id as u32
+        (self.pai_num as u32) * 4 + self.id as u32 - 1
+    }
+
+    /// 么九牌(一九字牌)かどうか
+    pub fn is_yaochu(&self) -> bool {
+        self.pai_num >= 27 || matches!(self.pai_num % 9, 0 | 8)
     }
 }
 
@@ -170,11 +190,24 @@ impl PlayerT {
     pub fn is_registered(&self) -> bool {
         self.name.n1[0] != 0
     }
+
+    /// 暗槓以外の副露がなければ門前
+    pub fn is_menzen(&self) -> bool {
+        self.mentsu[..self.mentsu_len as usize]
+            .iter()
+            .all(|m| m.mentsu_type == MentsuType::TYPE_ANKAN)
+    }
 }
 
 pub trait TakuControl {
     fn load(list: &[u32]) -> Self;
     fn create_shuffled() -> Self;
+    /// 与えた乱数生成器で牌をシャッフルする
+    fn create_shuffled_with<R: Rng>(rng: &mut R) -> Self;
+    /// シードから牌山を再現する
+    fn create_seeded(seed: u64) -> Self;
+    /// 三麻用に2m〜8mを除いた108枚の牌山を作る
+    fn create_sanma_shuffled_with<R: Rng>(rng: &mut R) -> Self;
     fn search(&self, target: &PaiT) -> anyhow::Result<usize>;
     fn get(&self, index: usize) -> anyhow::Result<PaiT>;
     fn get_range(&self, r: Range<usize>) -> anyhow::Result<Vec<PaiT>>;
@@ -190,39 +223,65 @@ impl GetTsumo for PlayerT {
     }
 }
 
-impl TakuControl for Taku {
-    fn create_shuffled() -> Self {
-        // Pai配列の初期化
-        let mut hai_array: Vec<Pai> = Vec::new();
-        let mut rng = rand::thread_rng();
-        let mut dst = [Pai::new(0, 0, false, false, false); 32];
-        let mut dst2 = [Pai::new(0, 0, false, false, false); 8];
-        let mut s = Self::new(&dst, &dst, &dst, &dst, &dst2, 0);
-
-        for pai_num in 0..34u8 {
-            for id in 1..=4u8 {
-                hai_array.push(Pai::new(pai_num, id, false, false, false));
-            }
+/// 牌の列から牌山を作る
+/// 136枚に満たない場合(三麻)は残りを空けて枚数をlengthに入れる
+fn taku_from_pai_list(hai_array: &[Pai]) -> Taku {
+    let mut buf = [Pai::new(0, 0, false, false, false, false); 136];
+    buf[..hai_array.len()].copy_from_slice(hai_array);
+
+    let mut dst = [Pai::new(0, 0, false, false, false, false); 32];
+    let mut dst2 = [Pai::new(0, 0, false, false, false, false); 8];
+    let mut s = Taku::new(&dst, &dst, &dst, &dst, &dst2, 0);
+
+    // 配列へコピー
+    dst.copy_from_slice(&buf[0..32]);
+    s.set_n1(&dst);
+    dst.copy_from_slice(&buf[32..64]);
+    s.set_n2(&dst);
+    dst.copy_from_slice(&buf[64..96]);
+    s.set_n3(&dst);
+    dst.copy_from_slice(&buf[96..128]);
+    s.set_n4(&dst);
+    dst2.copy_from_slice(&buf[128..136]);
+    s.set_n5(&dst2);
+
+    s.set_length(hai_array.len() as u32);
+
+    s
+}
+
+/// 指定した種類の牌を4枚ずつ並べてシャッフルする
+fn shuffled_taku<R: Rng>(rng: &mut R, pai_nums: impl Iterator<Item = u8>) -> Taku {
+    // Pai配列の初期化
+    let mut hai_array: Vec<Pai> = Vec::new();
+
+    for pai_num in pai_nums {
+        for id in 1..=4u8 {
+            hai_array.push(Pai::new(pai_num, id, false, false, false, false));
         }
+    }
 
-        // shuffleの実行
-        hai_array.shuffle(&mut rng);
+    // shuffleの実行
+    hai_array.shuffle(rng);
 
-        // 配列へコピー
-        dst.copy_from_slice(&hai_array[0..32]);
-        s.set_n1(&dst);
-        dst.copy_from_slice(&hai_array[32..64]);
-        s.set_n2(&dst);
-        dst.copy_from_slice(&hai_array[64..96]);
-        s.set_n3(&dst);
-        dst.copy_from_slice(&hai_array[96..128]);
-        s.set_n4(&dst);
-        dst2.copy_from_slice(&hai_array[128..136]);
-        s.set_n5(&dst2);
+    taku_from_pai_list(&hai_array)
+}
 
-        s.set_length(136);
+impl TakuControl for Taku {
+    fn create_shuffled() -> Self {
+        Self::create_shuffled_with(&mut rand::thread_rng())
+    }
 
-        s
+    fn create_shuffled_with<R: Rng>(rng: &mut R) -> Self {
+        shuffled_taku(rng, 0..34u8)
+    }
+
+    fn create_seeded(seed: u64) -> Self {
+        Self::create_shuffled_with(&mut ChaCha20Rng::seed_from_u64(seed))
+    }
+
+    fn create_sanma_shuffled_with<R: Rng>(rng: &mut R) -> Self {
+        shuffled_taku(rng, (0..34u8).filter(|n| !(1..8).contains(n)))
     }
 
     fn search(&self, target: &PaiT) -> anyhow::Result<usize> {
@@ -239,27 +298,36 @@ impl TakuControl for Taku {
 
     fn load(list: &[u32]) -> Self {
         let hai_array: Vec<Pai> = list
-            .into_iter()
-            .map(|x| Pai::new((x >> 2) as u8, (x & 3) as u8, false, false, false))
+            .iter()
+            .map(|x| {
+                Pai::new(
+                    (x >> 2) as u8,
+                    (x & 3) as u8 + 1,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
+            })
             .collect();
-        let mut dst = [Pai::new(0, 0, false, false, false); 32];
-        let mut dst2 = [Pai::new(0, 0, false, false, false); 8];
-        let mut s = Self::new(&dst, &dst, &dst, &dst, &dst2, 0);
-
-        dst.copy_from_slice(&hai_array[0..32]);
-        s.set_n1(&dst);
-        dst.copy_from_slice(&hai_array[32..64]);
-        s.set_n2(&dst);
-        dst.copy_from_slice(&hai_array[64..96]);
-        s.set_n3(&dst);
-        dst.copy_from_slice(&hai_array[96..128]);
-        s.set_n4(&dst);
-        dst2.copy_from_slice(&hai_array[128..136]);
-        s.set_n5(&dst2);
-
-        s.set_length(136);
 
-        s
+        taku_from_pai_list(&hai_array)
+    }
+}
+
+impl TakuT {
+    /// ルールの赤牌の枚数に従って赤5に印を付ける
+    pub fn set_aka(&mut self, aka_type: u32) {
+        for pai in self
+            .n1
+            .iter_mut()
+            .chain(self.n2.iter_mut())
+            .chain(self.n3.iter_mut())
+            .chain(self.n4.iter_mut())
+            .chain(self.n5.iter_mut())
+        {
+            pai.is_aka = is_aka(pai.pai_num, pai.id, aka_type);
+        }
     }
 }
 
@@ -268,6 +336,18 @@ impl TakuControl for TakuT {
         Taku::create_shuffled().unpack()
     }
 
+    fn create_shuffled_with<R: Rng>(rng: &mut R) -> Self {
+        Taku::create_shuffled_with(rng).unpack()
+    }
+
+    fn create_seeded(seed: u64) -> Self {
+        Taku::create_seeded(seed).unpack()
+    }
+
+    fn create_sanma_shuffled_with<R: Rng>(rng: &mut R) -> Self {
+        Taku::create_sanma_shuffled_with(rng).unpack()
+    }
+
     fn search(&self, target: &PaiT) -> anyhow::Result<usize> {
         if let Some(idx) = self.n1.iter().position(|item| item == target) {
             return Ok(idx);
@@ -288,6 +368,8 @@ impl TakuControl for TakuT {
     }
 
     fn get(&self, index: usize) -> anyhow::Result<PaiT> {
+        ensure!(index < self.length as usize, "index out of range");
+
         if index < 32 {
             return Ok(self.n1[index].clone());
         }
@@ -1,25 +1,170 @@
 use crate::{
-    agari::{add_machi_to_mentsu, Agari, AgariBehavior},
-    fbs_utils::TakuControl,
-    mahjong_generated::open_mahjong::{ActionType, GameStateT, PaiT, PlayerT, RuleT, TakuT},
-    play_log::PlayLog,
+    agari::{add_machi_to_mentsu, Agari, AgariBehavior, AgariType},
+    error::{ensure, GameError, Result},
+    fair_shuffle::{get_server_seed_hash, get_wall_hash, ShuffleSeed},
+    fbs_utils::{is_aka, TakuControl},
+    mahjong_generated::open_mahjong::{
+        ActionType, GameStateT, Mentsu, MentsuFlag, MentsuPaiT, MentsuT, MentsuType, PaiT, PlayerT,
+        PlayerViewT, PublicPlayerT, RuleT, TakuT,
+    },
+    payment::PaymentBehavior,
+    play_log::{AgariLogEntry, PlayLog},
     shanten::{all_of_mentsu, PaiState},
 };
-use anyhow::{bail, ensure};
 use chrono::Utc;
 use itertools::Itertools;
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use uuid::Uuid;
 
 const DORA_START_INDEX: usize = 0;
 const URADORA_START_INDEX: usize = 5;
+const RINSHAN_START_INDEX: usize = 10;
 const START_OF_YAMA: [u32; 4] = [14, 45, 75, 105];
+const START_OF_YAMA_SANMA: [u32; 3] = [14, 45, 76];
+const TSUMOHAI_INDEX: usize = 13;
+// oyanagare_typeのビット
+const OYANAGARE_AGARI: u32 = 0x01;
+const OYANAGARE_TENPAI: u32 = 0x02;
+const OYANAGARE_ABORTIVE: u32 = 0x04;
+const OYANAGARE_ALL_LAST_TENPAI: u32 = 0x08;
+
+const PEI: u8 = 30;
+
+/// 手牌から指定したインデックスの牌を抜き出す
+fn remove_tehai(player: &mut PlayerT, indices: &[usize]) -> Vec<PaiT> {
+    let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut removed: Vec<PaiT> = sorted.into_iter().map(|i| tehai.remove(i)).collect();
+    removed.sort_unstable();
+
+    player.tehai = Default::default();
+    for (i, item) in tehai.iter().enumerate() {
+        player.tehai[i] = item.clone();
+    }
+    player.tehai_len = tehai.len() as u32;
+
+    removed
+}
+
+/// サイコロを2つ振る
+fn roll_dice<R: Rng>(rng: &mut R) -> [u8; 2] {
+    [rng.gen_range(1..=6), rng.gen_range(1..=6)]
+}
+
+/// 鳴き用のパラメータ(手牌のインデックス2つ)を展開する
+fn unpack_naki_param(param: u32) -> [usize; 2] {
+    [(param & 0xff) as usize, ((param >> 8) & 0xff) as usize]
+}
+
+fn to_mentsu_pai(pai: &PaiT, flag: MentsuFlag) -> MentsuPaiT {
+    MentsuPaiT {
+        pai_num: pai.pai_num,
+        id: pai.id,
+        flag,
+    }
+}
+
+/// 喰い替えになる牌番号 (鳴いた牌と、順子の場合は反対側の筋の牌)
+/// numsには面子の牌番号を昇順で指定する
+fn kuikae_pai_nums(naki_pai_num: u8, nums: &[u8; 3]) -> Vec<u8> {
+    let mut ret = vec![naki_pai_num];
+
+    if nums[0] != nums[1] {
+        if naki_pai_num == nums[0] && naki_pai_num % 9 < 6 {
+            ret.push(naki_pai_num + 3);
+        } else if naki_pai_num == nums[2] && naki_pai_num % 9 >= 3 {
+            ret.push(naki_pai_num - 3);
+        }
+    }
+
+    ret
+}
+
+/// 手牌から出した牌と鳴いた牌で面子を作り、副露に加える
+fn push_mentsu(
+    player: &mut PlayerT,
+    tehai: &[PaiT],
+    naki_pai: Option<(&PaiT, MentsuFlag)>,
+    mentsu_type: MentsuType,
+) {
+    let mut pai_list: Vec<MentsuPaiT> = tehai
+        .iter()
+        .map(|p| to_mentsu_pai(p, MentsuFlag::FLAG_NONE))
+        .collect();
+    if let Some((pai, flag)) = naki_pai {
+        pai_list.push(to_mentsu_pai(pai, flag));
+    }
+    pai_list.sort_unstable_by_key(|p| (p.pai_num, p.id));
+
+    let mut mentsu = MentsuT {
+        pai_len: pai_list.len() as u32,
+        mentsu_type,
+        ..Default::default()
+    };
+    for (i, item) in pai_list.into_iter().enumerate() {
+        mentsu.pai_list[i] = item;
+    }
+
+    player.mentsu[player.mentsu_len as usize] = mentsu;
+    player.mentsu_len += 1;
+}
+
+/// プレイヤーが選択できる行動
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegalAction {
+    /// 打牌 (手牌のインデックス、13はツモ牌)
+    Sutehai(usize),
+    /// リーチして打牌
+    Riichi(usize),
+    /// ツモ和了
+    Tsumo,
+    Ron,
+    /// チー (手牌から出す2枚のインデックス)
+    Chii(u32),
+    /// ポン (手牌から出す2枚のインデックス)
+    Pon(u32),
+    Ankan(usize),
+    Kakan(usize),
+    Minkan,
+    /// 九種九牌
+    Nagashi,
+    /// 三麻の北抜き (手牌のインデックス)
+    Kita(usize),
+    /// 鳴き・ロンを見送る (手番のプレイヤーはツモに進む)
+    Pass,
+}
+
+impl LegalAction {
+    /// GameStateT::actionに渡す(ActionType, param)に変換する
+    pub fn to_action(&self) -> (ActionType, u32) {
+        match *self {
+            LegalAction::Sutehai(index) => (ActionType::ACTION_SUTEHAI, index as u32),
+            LegalAction::Riichi(index) => (ActionType::ACTION_RIICHI, index as u32),
+            LegalAction::Tsumo => (ActionType::ACTION_TSUMO, 0),
+            LegalAction::Ron => (ActionType::ACTION_RON, 0),
+            LegalAction::Chii(param) => (ActionType::ACTION_CHII, param),
+            LegalAction::Pon(param) => (ActionType::ACTION_PON, param),
+            LegalAction::Ankan(index) | LegalAction::Kakan(index) => {
+                (ActionType::ACTION_KAN, index as u32)
+            }
+            LegalAction::Minkan => (ActionType::ACTION_KAN, 0),
+            LegalAction::Nagashi => (ActionType::ACTION_NAGASHI, 0),
+            LegalAction::Kita(index) => (ActionType::ACTION_KITA, index as u32),
+            LegalAction::Pass => (ActionType::ACTION_SYNC, 0),
+        }
+    }
+}
 
 impl RuleT {
     pub fn update_to_default(&mut self) {
         self.enable_kuitan = true;
         self.enable_kansaki = false;
         self.enable_pao = false;
+        // 0: 頭ハネ, 1: ダブロンまで(トリプルロンは流局), 2: トリプルロンまで
+        self.double_ron_type = 1;
         self.initial_score = 25000;
         self.enable_tobi = true;
         self.enable_wareme = false;
@@ -30,18 +175,42 @@ impl RuleT {
         self.uradora_type = 2;
         self.enable_minus_riichi = true;
         self.enable_ryanhan_shibari = false;
+        // 0: フリテンリーチ可, 1: フリテンリーチ不可
+        self.furiten_riichi_type = 0;
         self.enable_keiten = true;
+        self.enable_glass_pai = false;
         self.oyanagare_type = 0x0f;
-        self.kan_in_riichi = 1;
+        self.kan_in_riichi_type = 1;
         self.enable_kiriage = false;
+        self.enable_agariyame = false;
+        self.enable_tonpu = false;
+        // 三麻のツモ和了 0: ツモ損, 1: 北家折半
+        self.sanma_tsumo_type = 0;
+        self.enable_atozuke = true;
+        // 人和 0: なし, 1: 役満, 2: 満貫
+        self.renhou_type = 0;
     }
 }
 
 impl GameStateT {
     pub fn create(&mut self, title: &[u8], player_len: u32, play_log: &mut PlayLog) {
+        self.create_with_seed(title, player_len, rand::random(), play_log);
+    }
+
+    /// シードを指定して対局を作成する
+    /// 同じシードなら席順と牌山が再現される
+    pub fn create_with_seed(
+        &mut self,
+        title: &[u8],
+        player_len: u32,
+        seed: u64,
+        play_log: &mut PlayLog,
+    ) {
         self.player_len = player_len;
         self.rule.update_to_default();
         self.title = title.into();
+        self.seed = seed;
+        self.shuffle_count = 0;
         let uuid = Uuid::new_v4();
         self.game_id = uuid.into_bytes();
         let dt = Utc::now();
@@ -51,30 +220,24 @@ impl GameStateT {
             player.score = self.rule.initial_score as i32;
         }
 
-        play_log.append_game_log(uuid.hyphenated().to_string(), dt.timestamp() as u64);
+        play_log.append_game_log(uuid.hyphenated().to_string(), dt.timestamp() as u64, seed);
     }
 
-    pub fn register_player(
-        &mut self,
-        name: &[u8],
-        play_log: &mut PlayLog,
-    ) -> anyhow::Result<usize> {
-        // registered == falseなplayerのindexのリストを作る
-        let unregistered_index = self
-            .players
-            .iter()
-            .enumerate()
-            .filter(|(_, x)| !x.is_registered())
-            .map(|(i, _)| i)
-            .collect_vec();
-        ensure!(unregistered_index.len() != 0, "player is full");
+    /// シードから用途ごとに独立した乱数生成器を作る
+    fn get_rng(&self, stream: u64) -> ChaCha20Rng {
+        let mut rng = ChaCha20Rng::seed_from_u64(self.seed);
+        rng.set_stream(stream);
+        rng
+    }
 
-        // unregistered_indexからランダムに選ぶ
-        let mut rng = rand::thread_rng();
-        let chosen_index = unregistered_index.choose(&mut rng);
+    pub fn register_player(&mut self, name: &[u8], play_log: &mut PlayLog) -> Result<usize> {
+        // シードから決まる席順のうち、最初の空席を選ぶ
+        let mut seats = (0..self.player_len as usize).collect_vec();
+        seats.shuffle(&mut self.get_rng(0));
+        let chosen_index = seats.iter().find(|i| !self.players[**i].is_registered());
 
         // chosen_indexがNoneならば、エラー
-        ensure!(chosen_index.is_some(), "player index choose error");
+        ensure!(chosen_index.is_some(), GameError::PlayerFull);
 
         let index = chosen_index.unwrap();
         let uuid = Uuid::from_bytes_ref(&self.game_id);
@@ -83,7 +246,7 @@ impl GameStateT {
 
         play_log.append_game_player_log(
             uuid.hyphenated().to_string(),
-            String::from_utf8(name.to_vec())?,
+            String::from_utf8(name.to_vec()).map_err(|_| GameError::InvalidPlayerName)?,
             *index as i32,
         );
 
@@ -96,12 +259,88 @@ impl GameStateT {
             .all(|x| x.is_registered())
     }
 
+    /// シードから次の牌山を作る
     pub fn shuffle(&mut self) {
-        self.taku = TakuT::create_shuffled()
+        self.shuffle_count += 1;
+        let mut rng = self.get_rng(self.shuffle_count as u64);
+        self.taku = if self.is_sanma() {
+            TakuT::create_sanma_shuffled_with(&mut rng)
+        } else {
+            TakuT::create_shuffled_with(&mut rng)
+        };
+        self.taku.set_aka(self.rule.aka_type);
+        self.dice = roll_dice(&mut rng);
+        self.is_fair_shuffle = false;
+        self.wall_hash = [0; 32];
+    }
+
+    /// 次の局のサーバーシードをコミットし、クライアントシードの受付を始める
+    /// 戻り値のハッシュはクライアントシードを受け付ける前に公開する
+    pub fn commit_server_seed(&mut self, server_seed: &[u8; 32]) -> [u8; 32] {
+        self.committed_server_seed = *server_seed;
+        self.server_seed_hash = get_server_seed_hash(server_seed);
+        self.is_seed_committed = true;
+        self.client_seeds = [0; 4];
+
+        self.server_seed_hash
+    }
+
+    /// 次の局の牌山に混ぜるクライアントシードを登録する
+    /// サーバーシードのコミット後から牌山を作るまでの間だけ受け付ける
+    pub fn set_client_seed(&mut self, player_index: usize, seed: u64) -> Result<()> {
+        ensure!(
+            player_index < self.player_len as usize,
+            GameError::InvalidPlayerIndex
+        );
+        ensure!(self.is_seed_committed, GameError::SeedNotCommitted);
+
+        self.client_seeds[player_index] = seed;
+        Ok(())
+    }
+
+    /// コミット済みのサーバーシードとクライアントシードから検証可能な牌山を作る
+    /// 戻り値のコミットメントは局の開始時に公開する
+    pub fn shuffle_fair(&mut self) -> Result<[u8; 32]> {
+        ensure!(self.is_seed_committed, GameError::SeedNotCommitted);
+
+        self.shuffle_count += 1;
+        self.server_seed = self.committed_server_seed;
+        self.is_fair_shuffle = true;
+        // 牌山を作った後はクライアントシードを変更できない
+        self.is_seed_committed = false;
+        // 次の局のシードが登録されても公開できるよう、使ったシードを残しておく
+        self.shuffle_client_seeds = self.client_seeds;
+
+        let seed = self.get_shuffle_seed();
+        self.taku = seed.create_taku();
+        self.taku.set_aka(self.rule.aka_type);
+        self.dice = roll_dice(&mut self.get_rng(self.shuffle_count as u64));
+        self.wall_hash = get_wall_hash(&self.taku, &self.server_seed);
+
+        Ok(self.wall_hash)
+    }
+
+    fn get_shuffle_seed(&self) -> ShuffleSeed {
+        ShuffleSeed {
+            game_id: self.game_id,
+            kyoku_index: self.shuffle_count,
+            server_seed: self.server_seed,
+            client_seeds: self.shuffle_client_seeds[..self.player_len as usize].to_vec(),
+            is_sanma: self.is_sanma(),
+        }
+    }
+
+    /// 局の終了後に牌山のシードを公開する
+    pub fn reveal_shuffle_seed(&self) -> Result<ShuffleSeed> {
+        ensure!(self.is_fair_shuffle, GameError::NoShuffleSeed);
+        ensure!(self.is_kyoku_end, GameError::KyokuNotEnded);
+
+        Ok(self.get_shuffle_seed())
     }
 
     pub fn load(&mut self, hai_ids: &[u32]) {
         self.taku = TakuT::load(hai_ids);
+        self.taku.set_aka(self.rule.aka_type);
     }
 
     pub fn next_cursol(&mut self) {
@@ -122,25 +361,123 @@ impl GameStateT {
         }
     }
 
+    /// 三人麻雀かどうか
+    pub fn is_sanma(&self) -> bool {
+        self.player_len == 3
+    }
+
+    /// 割れ目のプレイヤー (親から数えてサイコロの目の位置)
+    /// サイコロを振っていない場合や割れ目なしのルールではNone
+    pub fn get_wareme_player(&self) -> Option<usize> {
+        let sum = (self.dice[0] + self.dice[1]) as u32;
+
+        if !self.rule.enable_wareme || sum == 0 {
+            return None;
+        }
+
+        Some(((self.oya + sum - 1) % self.player_len) as usize)
+    }
+
+    fn get_start_of_yama(&self, idx: usize) -> u32 {
+        if self.is_sanma() {
+            START_OF_YAMA_SANMA[idx]
+        } else {
+            START_OF_YAMA[idx]
+        }
+    }
+
     pub fn remain(&self) -> u32 {
-        if self.is_non_duplicate {
-            136 - self.taku_cursol
+        // 嶺上牌を取った分だけ海底が繰り上がる
+        let rinshan_count = self.get_rinshan_count() as u32;
+
+        // 開始前の状態でもパニックしないように0で止める
+        let used = if self.is_non_duplicate {
+            self.taku_cursol + rinshan_count
         } else {
-            136 - 14
-                - self.players[0..self.player_len as usize]
+            14 + rinshan_count
+                + self.players[0..self.player_len as usize]
                     .iter()
                     .enumerate()
-                    .map(|(idx, x)| x.cursol - START_OF_YAMA[idx])
+                    .map(|(idx, x)| x.cursol.saturating_sub(self.get_start_of_yama(idx)))
                     .sum::<u32>()
+        };
+
+        self.taku.length.saturating_sub(used)
+    }
+
+    /// 牌山の最後の牌までツモしたかどうか (牌山がない開始前はfalse)
+    pub fn is_last_pai(&self) -> bool {
+        self.taku.length > 0 && self.remain() == 0
+    }
+
+    /// 嶺上牌を取った回数 (槓と北抜き)
+    fn get_rinshan_count(&self) -> usize {
+        self.get_kan_count()
+            + self.players[..self.player_len as usize]
+                .iter()
+                .map(|p| p.nukidora as usize)
+                .sum::<usize>()
+    }
+
+    /// 場に出ている槓子の数
+    pub fn get_kan_count(&self) -> usize {
+        self.players[..self.player_len as usize]
+            .iter()
+            .flat_map(|p| p.mentsu[..p.mentsu_len as usize].iter())
+            .filter(|m| {
+                m.mentsu_type == MentsuType::TYPE_ANKAN || m.mentsu_type == MentsuType::TYPE_MINKAN
+            })
+            .count()
+    }
+
+    /// 槓ドラをめくる
+    fn update_kan_dora(&mut self) {
+        let dora_len = 1 + self.get_kan_count() as u32;
+
+        if self.dora_len < dora_len {
+            self.dora_len = dora_len;
+            self.uradora_len = dora_len;
         }
     }
 
+    /// 嶺上牌をツモる
+    fn rinshan_tsumo(&mut self, play_log: &mut PlayLog, player_index: usize) -> Result<()> {
+        // 王牌の嶺上牌(4枚)を使い切ったら、海底側から補充した牌を取る
+        let count = self.get_rinshan_count();
+        let index = if count <= 4 {
+            RINSHAN_START_INDEX + count - 1
+        } else {
+            self.taku.length as usize - (count - 4)
+        };
+        let player = &mut self.players[player_index];
+
+        player.tsumohai = self.taku.get(index).map_err(|_| GameError::WallExhausted)?;
+        player.is_tsumo = true;
+        player.is_rinshan = true;
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("tsumo"),
+            player.tsumohai.get_pai_id(),
+        );
+        self.seq += 1;
+
+        Ok(())
+    }
+
     pub fn start(&mut self, play_log: &mut PlayLog) {
         // 配牌
         self.taku_cursol = 14;
         self.dora_len = 1;
         self.uradora_len = 1;
         self.seq = 0;
+        self.teban = self.oya;
+        self.is_kyoku_end = false;
+        self.is_renchan = false;
+        self.is_ryuukyoku = false;
+        self.is_chankan = false;
         let dt = Utc::now();
         self.kyoku_id = (dt.timestamp() / (24 * 3600) * 100000) as u64;
         let mut kazes = [Some(0), Some(0), Some(0), Some(0)];
@@ -154,7 +491,7 @@ impl GameStateT {
         play_log.append_kyoku_log(
             self.kyoku_id,
             uuid.hyphenated().to_string(),
-            0,
+            self.get_kyoku_num() as i32,
             self.tsumobou as i32,
             self.riichibou as i32,
             &self
@@ -165,14 +502,30 @@ impl GameStateT {
             &kazes,
         );
 
+        // 牌山のコミットメントを残し、シードの公開後に検証できるようにする
+        if self.is_fair_shuffle {
+            play_log.append_wall_hash_log(self.kyoku_id, &self.wall_hash);
+        }
+
         for idx in 0..self.player_len {
+            let start_of_yama = self.get_start_of_yama(idx as usize);
             let player = &mut self.players[idx as usize];
             let cursol: &mut u32;
 
-            player.cursol = 14 + (idx * if idx < 2 { 31 } else { 30 });
+            player.cursol = start_of_yama;
             player.kawahai_len = 0;
+            player.nukidora = 0;
+            player.is_pao = false;
+            player.pao_player = 0;
+            player.is_rinshan = false;
+            player.is_double_riichi = false;
+            player.mentsu_len = 0;
+            player.is_tsumo = false;
             player.is_ippatsu = false;
             player.is_riichi = false;
+            player.is_doujun_furiten = false;
+            player.is_riichi_furiten = false;
+            player.is_responded = false;
 
             if self.is_non_duplicate {
                 cursol = &mut self.taku_cursol;
@@ -208,14 +561,105 @@ impl GameStateT {
         self.players[index].clone()
     }
 
-    pub fn tsumo(&mut self, play_log: &mut PlayLog) -> anyhow::Result<()> {
+    /// 指定した席から見える情報だけを取り出す
+    /// 他家の手牌・牌山・裏ドラは含まない
+    pub fn get_player_view(&self, player_index: usize) -> Result<PlayerViewT> {
+        ensure!(
+            player_index < self.player_len as usize,
+            GameError::InvalidPlayerIndex
+        );
+
+        let player = &self.players[player_index];
+        let mut view = PlayerViewT {
+            player_index: player_index as u32,
+            tehai: player.tehai.clone(),
+            tehai_len: player.tehai_len,
+            tsumohai: player.tsumohai.clone(),
+            is_tsumo: player.is_tsumo,
+            is_furiten: self.is_furiten(player_index),
+            player_len: self.player_len,
+            bakaze: self.bakaze,
+            oya: self.oya,
+            tsumobou: self.tsumobou,
+            riichibou: self.riichibou,
+            teban: self.teban,
+            remain: self.remain(),
+            dora_len: self.dora_len,
+            is_kyoku_end: self.is_kyoku_end,
+            wall_hash: self.wall_hash,
+            server_seed_hash: self.server_seed_hash,
+            ..Default::default()
+        };
+
+        for (i, p) in self.players[..self.player_len as usize].iter().enumerate() {
+            view.players[i] = PublicPlayerT {
+                mentsu: p.mentsu.clone(),
+                mentsu_len: p.mentsu_len,
+                tehai_len: p.tehai_len,
+                kawahai: p.kawahai.clone(),
+                kawahai_len: p.kawahai_len,
+                is_tsumo: p.is_tsumo,
+                is_riichi: p.is_riichi,
+                score: p.score,
+                nukidora: p.nukidora,
+            };
+        }
+
+        for (i, pai) in self.get_dora().iter().enumerate() {
+            view.dora[i] = pai.clone();
+        }
+
+        Ok(view)
+    }
+
+    /// 次のプレイヤーがツモする
+    /// 直前の捨て牌があれば見送られたものとして手番を進める
+    pub fn tsumo(&mut self, play_log: &mut PlayLog) -> Result<()> {
+        let player = &self.players[self.teban as usize];
+        ensure!(
+            !player.is_tsumo && player.tehai_len % 3 == 1,
+            GameError::InvalidState
+        );
+        ensure!(!self.is_kyoku_end, GameError::KyokuEnded);
+
+        // 槍槓が見送られたら加槓したプレイヤーが嶺上牌をツモする
+        if self.is_chankan {
+            let who = self.teban as usize;
+            let pai = self.chankan_pai.clone();
+            self.update_furiten_on_pass(who, &pai, None);
+            self.is_chankan = false;
+
+            return self.kan_rinshan_tsumo(play_log, who);
+        }
+
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        if let Some((who, pai)) = self.get_last_sutehai() {
+            self.update_furiten_on_pass(who, &pai, None);
+
+            // 途中流局の条件を満たしていればツモせずに局を終える
+            if let Some(name) = self.get_abortive_draw() {
+                self.abortive_draw(play_log, name);
+                return Ok(());
+            }
+
+            self.teban = self.get_next_teban() as u32;
+        }
+
         let player = &mut self.players[self.teban as usize];
         player.is_tsumo = true;
+        player.is_rinshan = false;
 
         if self.is_non_duplicate {
-            player.tsumohai = self.taku.get(self.taku_cursol as usize)?;
+            player.tsumohai = self
+                .taku
+                .get(self.taku_cursol as usize)
+                .map_err(|_| GameError::WallExhausted)?;
         } else {
-            player.tsumohai = self.taku.get(player.cursol as usize)?;
+            player.tsumohai = self
+                .taku
+                .get(player.cursol as usize)
+                .map_err(|_| GameError::WallExhausted)?;
         }
 
         play_log.append_actions_log(
@@ -232,49 +676,91 @@ impl GameStateT {
         Ok(())
     }
 
-    pub fn sutehai(
-        &mut self,
-        play_log: &mut PlayLog,
-        index: usize,
-        is_riichi: bool,
-    ) -> anyhow::Result<()> {
-        let player = &mut self.players[self.teban as usize];
-        let mut tehai: Vec<PaiT> = player.tehai.iter().cloned().collect();
-        let mut kawahai = match index {
-            13 => player.tsumohai.clone(),
+    /// 手番のプレイヤーが打牌できるかを確かめ、捨てる牌と打牌後の手牌を返す
+    fn check_sutehai(&self, index: usize, is_riichi: bool) -> Result<(PaiT, Vec<PaiT>)> {
+        ensure!(!self.is_kyoku_end, GameError::KyokuEnded);
+
+        let kuikae = self.get_kuikae_pai_nums(self.teban as usize);
+        let player = &self.players[self.teban as usize];
+        let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+
+        ensure!(
+            player.is_tsumo || player.tehai_len % 3 == 2,
+            GameError::InvalidState
+        );
+
+        let kawahai = match index {
+            TSUMOHAI_INDEX => {
+                ensure!(player.is_tsumo, GameError::InvalidState);
+                player.tsumohai.clone()
+            }
             _ => {
+                ensure!(index < tehai.len(), GameError::InvalidTileIndex);
                 let p = tehai.remove(index);
-                tehai.push(player.tsumohai.clone());
+                if player.is_tsumo {
+                    tehai.push(player.tsumohai.clone());
+                }
                 tehai.sort_unstable();
                 p
             }
         };
 
         ensure!(
-            !(player.is_riichi && index != 13),
-            "リーチ後はツモ切りのみです"
+            !(player.is_riichi && index != TSUMOHAI_INDEX),
+            GameError::InRiichi
         );
+        ensure!(!kuikae.contains(&kawahai.pai_num), GameError::Kuikae);
 
         if is_riichi {
-            ensure!(!player.is_riichi, "すでにリーチしています");
-            ensure!(player.mentsu_len == 0, "面前ではありません");
+            ensure!(!player.is_riichi, GameError::AlreadyRiichi);
+            ensure!(player.is_menzen(), GameError::NotMenzen);
+            ensure!(
+                self.rule.enable_minus_riichi || player.score >= 1000,
+                GameError::NotEnoughScore
+            );
             // シャンテン数チェック
             let mut state = PaiState::from(&tehai);
             let shanten = state.get_shanten(player.mentsu_len as usize);
-            ensure!(shanten == 0, "テンパイではありません");
+            ensure!(shanten == 0, GameError::NotTenpai);
+
+            if self.rule.furiten_riichi_type != 0 {
+                let machi = state.get_machi(player.mentsu_len as usize);
+                let is_furiten = player.kawahai[..player.kawahai_len as usize]
+                    .iter()
+                    .chain([&kawahai])
+                    .any(|p| machi.contains(&p.pai_num));
+                ensure!(!is_furiten, GameError::Furiten);
+            }
+        }
+
+        Ok((kawahai, tehai))
+    }
 
+    pub fn sutehai(&mut self, play_log: &mut PlayLog, index: usize, is_riichi: bool) -> Result<()> {
+        let (mut kawahai, tehai) = self.check_sutehai(index, is_riichi)?;
+        let is_first_turn = self.is_first_turn(self.teban as usize);
+        let player = &mut self.players[self.teban as usize];
+
+        if is_riichi {
             player.is_riichi = true;
+            player.is_double_riichi = is_first_turn;
             player.is_ippatsu = true;
             player.score -= 1000;
             kawahai.is_riichi = true;
+            self.riichibou += 1;
         } else {
             player.is_ippatsu = false;
         }
 
-        if index != 13 {
-            for (i, item) in tehai.into_iter().enumerate() {
-                player.tehai[i] = item;
+        // 同巡内フリテンは自分の打牌で解消する
+        player.is_doujun_furiten = false;
+
+        if index != TSUMOHAI_INDEX {
+            player.tehai = Default::default();
+            for (i, item) in tehai.iter().enumerate() {
+                player.tehai[i] = item.clone();
             }
+            player.tehai_len = tehai.len() as u32;
         }
 
         player.kawahai[player.kawahai_len as usize] = kawahai;
@@ -292,32 +778,104 @@ impl GameStateT {
         player.tsumohai = Default::default();
 
         player.is_tsumo = false;
-        self.teban += 1;
-        if self.teban == self.player_len {
-            self.teban = 0;
+        player.is_rinshan = false;
+
+        for player in self.players.iter_mut() {
+            player.is_responded = false;
         }
 
+        // 明槓の槓ドラは打牌後にめくる
+        self.update_kan_dora();
+
         Ok(())
     }
 
-    pub fn tsumo_agari(&mut self, play_log: &mut PlayLog) -> anyhow::Result<Agari> {
-        let player = &self.players[self.teban as usize];
-        let mut tehai: Vec<PaiT> = player.tehai.iter().cloned().collect();
-        let machipai = player.tsumohai.clone();
+    /// 和了牌を加えた手牌を評価し、最も点数の高い和了を返す
+    fn evaluate_agari(
+        &self,
+        who: usize,
+        machipai: &PaiT,
+        agari_type: AgariType,
+    ) -> Result<(Agari, Vec<PaiT>)> {
+        let ret = self.evaluate_machipai(who, machipai, agari_type)?;
+
+        // 後付けなしでは、副露した手はどの待ち牌で和了しても役がなければならない
+        if !self.rule.enable_atozuke && !self.players[who].is_menzen() {
+            for pai_num in self.get_player_machi(who) {
+                let pai = PaiT {
+                    pai_num,
+                    ..Default::default()
+                };
+                ensure!(
+                    self.evaluate_machipai(who, &pai, agari_type).is_ok(),
+                    GameError::NoYaku
+                );
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// 和了牌を1枚加えた手牌を評価する
+    fn evaluate_machipai(
+        &self,
+        who: usize,
+        machipai: &PaiT,
+        agari_type: AgariType,
+    ) -> Result<(Agari, Vec<PaiT>)> {
+        let player = &self.players[who];
+        let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+        let fulo: Vec<Mentsu> = player.mentsu[..player.mentsu_len as usize]
+            .iter()
+            .map(|m| m.pack())
+            .collect();
 
         tehai.push(machipai.clone());
 
         let mut state = PaiState::from(&tehai);
 
-        let all_mentsu = all_of_mentsu(&mut state, 0);
-        let all_mentsu_w_machi = add_machi_to_mentsu(&all_mentsu, &player.tsumohai.pack());
+        let all_mentsu = all_of_mentsu(&mut state, fulo.len());
+        let all_mentsu_w_machi = add_machi_to_mentsu(&all_mentsu, &machipai.pack());
+
+        ensure!(all_mentsu_w_machi.len() > 0, GameError::NotAgari);
+
+        let aka_type = self.rule.aka_type;
+        let akadora = tehai
+            .iter()
+            .filter(|p| is_aka(p.pai_num, p.id, aka_type))
+            .count()
+            + player.mentsu[..player.mentsu_len as usize]
+                .iter()
+                .flat_map(|m| m.pai_list[..m.pai_len as usize].iter())
+                .filter(|p| is_aka(p.pai_num, p.id, aka_type))
+                .count();
+
+        let best_agari = self
+            .get_best_agari(
+                who,
+                &all_mentsu_w_machi,
+                &fulo,
+                player.nukidora as usize,
+                akadora,
+                agari_type,
+            )
+            .map_err(|_| GameError::NotAgari)?;
 
-        ensure!(all_mentsu_w_machi.len() > 0, "和了ではありません");
+        ensure!(best_agari.score > 0, GameError::NoYaku);
 
-        let best_agari =
-            self.get_best_agari(self.teban as usize, &all_mentsu_w_machi, &Vec::new(), 0)?;
-        self.players[self.teban as usize].score += best_agari.score;
+        Ok((best_agari, tehai))
+    }
 
+    /// 和了を記録する (和了牌は手牌の末尾)
+    fn append_agari_log(
+        &self,
+        play_log: &mut PlayLog,
+        who: usize,
+        by: usize,
+        tehai: &[PaiT],
+        agari: &Agari,
+        score_diff: &[i32],
+    ) {
         let dora_orig = self
             .get_dora()
             .iter()
@@ -329,77 +887,1442 @@ impl GameStateT {
             .map(|x| Some(x.get_pai_id()))
             .collect_vec();
 
-        play_log.append_agaris_log(
-            self.kyoku_id,
-            machipai.get_pai_id(),
-            best_agari.score,
-            best_agari.fu,
-            best_agari.han,
-            &tehai.iter().map(|x| Some(x.get_pai_id())).collect_vec(),
-            &best_agari.yaku,
-            &dora_orig,
-            &uradora_orig,
-            &dora_orig,
-            &uradora_orig,
-            self.teban as i32,
-            self.teban as i32,
-            &[Some(best_agari.score), Some(0), Some(0), Some(0)],
+        play_log.append_agaris_log(&AgariLogEntry {
+            kyoku_id: self.kyoku_id,
+            machipai: tehai[tehai.len() - 1].get_pai_id(),
+            score: agari.score,
+            fu: agari.fu,
+            han: agari.han,
+            pai_ids: &tehai.iter().map(|x| Some(x.get_pai_id())).collect_vec(),
+            yaku: &agari.yaku,
+            dora: &dora_orig,
+            uradora: &uradora_orig,
+            dora_orig: &dora_orig,
+            uradora_orig: &uradora_orig,
+            who: who as i32,
+            by: by as i32,
+            score_diff: &score_diff.iter().map(|x| Some(*x)).collect_vec(),
+            owari: false,
+            nukidora: self.players[who].nukidora,
+            pao: self.get_pao_player(who, by, agari).map_or(-1, |p| p as i32),
+            aka_type: self.rule.aka_type,
+        });
+    }
+
+    /// 手番のプレイヤーがツモ和了できるかを確かめ、和了と和了牌を加えた手牌を返す
+    fn check_tsumo_agari(&self) -> Result<(Agari, Vec<PaiT>)> {
+        let who = self.teban as usize;
+        let player = &self.players[who];
+
+        ensure!(!self.is_kyoku_end, GameError::KyokuEnded);
+        ensure!(player.is_tsumo, GameError::InvalidState);
+
+        self.evaluate_agari(who, &player.tsumohai, AgariType::Tsumo)
+    }
+
+    pub fn tsumo_agari(&mut self, play_log: &mut PlayLog) -> Result<Agari> {
+        let who = self.teban as usize;
+        let (best_agari, tehai) = self.check_tsumo_agari()?;
+        let score_diff = self.settle_agari(who, who, &best_agari, true);
+
+        self.append_agari_log(play_log, who, who, &tehai, &best_agari, &score_diff);
+        self.end_kyoku(
             false,
-            0,
+            who == self.oya as usize && self.rule.oyanagare_type & OYANAGARE_AGARI != 0,
         );
 
         Ok(best_agari)
     }
 
-    pub fn nagare(&mut self, play_log: &mut PlayLog) {
-        let score = [Some(-3000), Some(0), Some(0), Some(0)];
-        play_log.append_nagare_log(self.kyoku_id, String::from("流局"), &score);
+    /// 直前の捨て牌でロン和了できるかを確かめ、和了と和了牌を加えた手牌を返す
+    fn check_ron(&self, player_index: usize) -> Result<(Agari, Vec<PaiT>)> {
+        let (who, machipai) = match self.get_last_sutehai() {
+            Some(x) => x,
+            None => return Err(GameError::NoSutehai),
+        };
+
+        ensure!(
+            player_index < self.player_len as usize,
+            GameError::InvalidPlayerIndex
+        );
+        ensure!(who != player_index, GameError::OwnSutehai);
+        ensure!(!self.is_furiten(player_index), GameError::Furiten);
+
+        self.evaluate_agari(player_index, &machipai, AgariType::Ron)
+    }
+
+    /// 直前の捨て牌でロン和了する
+    /// 点数は放銃したプレイヤーのみが支払う
+    pub fn ron(&mut self, play_log: &mut PlayLog, player_index: usize) -> Result<Agari> {
+        let mut agaris = self.multi_ron(play_log, &[player_index])?;
+
+        Ok(agaris.remove(0))
     }
 
-    pub fn action(
-        &mut self,
-        play_log: &mut PlayLog,
-        action_type: ActionType,
-        player_index: usize,
-        param: u32,
-    ) -> anyhow::Result<()> {
-        match action_type {
-            ActionType::ACTION_RIICHI => {
-                if player_index == self.teban as usize {
-                    self.sutehai(play_log, param as usize, true);
-                    Ok(())
-                } else {
-                    bail!("not teban")
-                }
-            }
-            ActionType::ACTION_SYNC => {
-                if player_index == self.teban as usize {
-                    self.tsumo(play_log)
-                } else {
-                    Ok(())
-                }
-            }
-            ActionType::ACTION_SUTEHAI => {
-                if player_index == self.teban as usize {
-                    self.sutehai(play_log, param as usize, false);
-                    Ok(())
-                } else {
-                    bail!("not teban")
-                }
+    /// 直前の捨て牌に対する複数人のロン
+    /// double_ron_typeに従って頭ハネ・ダブロン・トリプルロンを処理する
+    /// 三家和で流局した場合は空のリストを返す
+    pub fn multi_ron(&mut self, play_log: &mut PlayLog, players: &[usize]) -> Result<Vec<Agari>> {
+        let (who, pai) = match self.get_last_sutehai() {
+            Some(x) => x,
+            None => return Err(GameError::NoSutehai),
+        };
+        let player_len = self.player_len as usize;
+
+        ensure!(!players.is_empty(), GameError::IllegalAction);
+
+        // 放銃したプレイヤーの下家から順に並べる
+        let mut winners = players.to_vec();
+        winners.sort_unstable_by_key(|p| (p + player_len - who) % player_len);
+        winners.dedup();
+
+        let mut agaris = Vec::new();
+        for player_index in winners {
+            let (agari, tehai) = self.check_ron(player_index)?;
+            agaris.push((player_index, agari, tehai));
+        }
+
+        // 立直宣言牌でロンされたら立直は成立せず、リーチ棒を返す
+        if pai.is_riichi {
+            self.players[who].score += 1000;
+            self.riichibou -= 1;
+        }
+
+        match self.rule.double_ron_type {
+            0 => agaris.truncate(1),
+            1 if agaris.len() >= 3 => {
+                self.abortive_draw(play_log, "三家和");
+                return Ok(vec![]);
             }
-            ActionType::ACTION_CHII => todo!(),
-            ActionType::ACTION_PON => todo!(),
-            ActionType::ACTION_KAN => todo!(),
-            ActionType::ACTION_TSUMO => {
-                if player_index == self.teban as usize {
-                    self.tsumo_agari(play_log)?;
-                    Ok(())
-                } else {
-                    bail!("not teban")
-                }
+            _ => {}
+        }
+
+        for (i, (player_index, agari, tehai)) in agaris.iter().enumerate() {
+            // 積み棒と供託は放銃者から最も近い和了者が受け取る
+            let score_diff = self.settle_agari(*player_index, who, agari, i == 0);
+            self.append_agari_log(play_log, *player_index, who, tehai, agari, &score_diff);
+        }
+
+        let is_oya_agari = agaris.iter().any(|(p, _, _)| *p == self.oya as usize);
+        self.end_kyoku(
+            false,
+            is_oya_agari && self.rule.oyanagare_type & OYANAGARE_AGARI != 0,
+        );
+
+        Ok(agaris.into_iter().map(|(_, agari, _)| agari).collect())
+    }
+
+    /// 最初のツモ番で、まだ誰も鳴いていないかどうか
+    pub fn is_first_turn(&self, who: usize) -> bool {
+        self.players[who].kawahai_len == 0
+            && self.players[..self.player_len as usize]
+                .iter()
+                .all(|p| p.mentsu_len == 0)
+    }
+
+    /// 流し満貫の条件を満たしているかどうか
+    /// 捨て牌がすべて么九牌で、1枚も鳴かれていないこと
+    pub fn is_nagashi_mangan(&self, who: usize) -> bool {
+        let player = &self.players[who];
+
+        player.kawahai_len > 0
+            && player.kawahai[..player.kawahai_len as usize]
+                .iter()
+                .all(|p| p.is_yaochu() && !p.is_nakare)
+    }
+
+    /// 九種九牌で流局できるかどうか
+    pub fn can_kyuushu(&self, who: usize) -> bool {
+        let player = &self.players[who];
+
+        if self.is_kyoku_end
+            || who != self.teban as usize
+            || !player.is_tsumo
+            || !self.is_first_turn(who)
+        {
+            return false;
+        }
+
+        player.tehai[..player.tehai_len as usize]
+            .iter()
+            .chain([&player.tsumohai])
+            .filter(|p| p.is_yaochu())
+            .map(|p| p.pai_num)
+            .unique()
+            .count()
+            >= 9
+    }
+
+    /// 九種九牌で流局する
+    pub fn kyuushu(&mut self, play_log: &mut PlayLog, player_index: usize) -> Result<()> {
+        ensure!(self.can_kyuushu(player_index), GameError::NotKyuushu);
+
+        self.abortive_draw(play_log, "九種九牌");
+
+        Ok(())
+    }
+
+    /// 捨て牌が通った時点で成立している途中流局を返す
+    fn get_abortive_draw(&self) -> Option<&'static str> {
+        if self.player_len != 4 {
+            return None;
+        }
+
+        let players = &self.players[..self.player_len as usize];
+
+        let first = &players[0].kawahai[0];
+        if players
+            .iter()
+            .all(|p| p.kawahai_len == 1 && p.mentsu_len == 0)
+            && (27..31).contains(&first.pai_num)
+            && players
+                .iter()
+                .all(|p| p.kawahai[0].pai_num == first.pai_num)
+        {
+            return Some("四風連打");
+        }
+
+        if players.iter().all(|p| p.is_riichi) {
+            return Some("四家立直");
+        }
+
+        // 一人で4回槓した場合は四槓子の可能性があるので続行する
+        let kan_players = players
+            .iter()
+            .filter(|p| {
+                p.mentsu[..p.mentsu_len as usize].iter().any(|m| {
+                    m.mentsu_type == MentsuType::TYPE_ANKAN
+                        || m.mentsu_type == MentsuType::TYPE_MINKAN
+                })
+            })
+            .count();
+        if self.get_kan_count() == 4 && kan_players > 1 {
+            return Some("四開槓");
+        }
+
+        None
+    }
+
+    /// 途中流局で局を終える (点数の移動はない)
+    fn abortive_draw(&mut self, play_log: &mut PlayLog, name: &str) {
+        play_log.append_nagare_log(self.kyoku_id, String::from(name), &[Some(0); 4]);
+        self.end_kyoku(true, self.rule.oyanagare_type & OYANAGARE_ABORTIVE != 0);
+    }
+
+    /// 流局時にテンパイしているかどうか
+    /// 形式テンパイが認められない場合は状態役を除いて役のある和了牌が必要
+    pub fn is_tenpai(&self, who: usize) -> bool {
+        let player = &self.players[who];
+
+        if player.is_riichi {
+            return true;
+        }
+
+        if self.rule.enable_keiten {
+            let tehai = player.tehai[..player.tehai_len as usize].to_vec();
+            return PaiState::from(&tehai).get_shanten(player.mentsu_len as usize) == 0;
+        }
+
+        self.get_player_machi(who).into_iter().any(|num| {
+            // 自分で4枚使っている牌は待ちにならない
+            let count = player.tehai[..player.tehai_len as usize]
+                .iter()
+                .filter(|p| p.pai_num == num)
+                .count();
+            let pai = PaiT {
+                pai_num: num,
+                ..Default::default()
+            };
+
+            count < 4 && self.evaluate_agari(who, &pai, AgariType::Tenpai).is_ok()
+        })
+    }
+
+    /// 荒牌流局
+    /// ノーテン罰符3000点をテンパイしたプレイヤーで分け合う
+    pub fn nagare(&mut self, play_log: &mut PlayLog) -> Result<[i32; 4]> {
+        ensure!(!self.is_kyoku_end, GameError::KyokuEnded);
+        ensure!(self.remain() == 0, GameError::WallNotExhausted);
+        ensure!(
+            !self.players[self.teban as usize].is_tsumo,
+            GameError::InvalidState
+        );
+
+        let player_len = self.player_len as usize;
+        let tenpai = (0..player_len).map(|i| self.is_tenpai(i)).collect_vec();
+        let tenpai_count = tenpai.iter().filter(|x| **x).count() as i32;
+        let nagashi = (0..player_len)
+            .filter(|i| self.is_nagashi_mangan(*i))
+            .collect_vec();
+        let mut score_diff = [0; 4];
+
+        // 流し満貫があればノーテン罰符の代わりに満貫を支払う
+        if !nagashi.is_empty() {
+            for who in nagashi.iter() {
+                let payment = self.get_nagashi_payment(*who);
+                for (diff, p) in score_diff.iter_mut().zip(payment) {
+                    *diff += p;
+                }
+            }
+        } else if tenpai_count > 0 && tenpai_count < player_len as i32 {
+            let noten_count = player_len as i32 - tenpai_count;
+
+            for (i, is_tenpai) in tenpai.iter().enumerate() {
+                score_diff[i] = if *is_tenpai {
+                    3000 / tenpai_count
+                } else {
+                    -3000 / noten_count
+                };
+            }
+        }
+
+        for (player, diff) in self.players.iter_mut().zip(score_diff.iter()) {
+            player.score += diff;
+        }
+
+        let name = if nagashi.is_empty() {
+            "流局"
+        } else {
+            "流し満貫"
+        };
+        play_log.append_nagare_log(
+            self.kyoku_id,
+            String::from(name),
+            &score_diff.iter().map(|x| Some(*x)).collect_vec(),
+        );
+
+        // オーラスのテンパイ連荘は別に指定する
+        let renchan_flag = if self.is_all_last() {
+            OYANAGARE_ALL_LAST_TENPAI
+        } else {
+            OYANAGARE_TENPAI
+        };
+        self.end_kyoku(
+            true,
+            tenpai[self.oya as usize] && self.rule.oyanagare_type & renchan_flag != 0,
+        );
+
+        Ok(score_diff)
+    }
+
+    /// 和了の点数を精算し、各席の点数移動を返す
+    fn settle_agari(&mut self, who: usize, by: usize, agari: &Agari, with_bonus: bool) -> [i32; 4] {
+        let mut score_diff = self.get_agari_payment(who, by, agari);
+
+        if with_bonus {
+            let bonus = self.get_bonus_payment(who, by);
+            for (diff, b) in score_diff.iter_mut().zip(bonus) {
+                *diff += b;
+            }
+            self.riichibou = 0;
+        }
+
+        for (player, diff) in self.players.iter_mut().zip(score_diff.iter()) {
+            player.score += diff;
+        }
+
+        score_diff
+    }
+
+    /// 供託のリーチ棒を受け取る
+    fn take_riichibou(&mut self) -> i32 {
+        let score = self.riichibou as i32 * 1000;
+        self.riichibou = 0;
+
+        score
+    }
+
+    /// 局を終了し、次局の連荘を決める
+    fn end_kyoku(&mut self, is_ryuukyoku: bool, is_renchan: bool) {
+        self.is_kyoku_end = true;
+        self.is_ryuukyoku = is_ryuukyoku;
+        self.is_renchan = is_renchan;
+    }
+
+    /// 局の通し番号 (東1局が0)
+    pub fn get_kyoku_num(&self) -> u32 {
+        self.bakaze * self.player_len + self.oya
+    }
+
+    /// 最後の場風 (東風戦は東、半荘戦は南)
+    fn get_last_bakaze(&self) -> u32 {
+        if self.rule.enable_tonpu {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// オーラスかどうか
+    pub fn is_all_last(&self) -> bool {
+        self.bakaze == self.get_last_bakaze() && self.oya + 1 == self.player_len
+    }
+
+    /// 順位順にプレイヤーのインデックスを返す
+    /// 同点の場合は起家に近いほうが上位
+    pub fn get_ranking(&self) -> Vec<usize> {
+        (0..self.player_len as usize)
+            .sorted_by_key(|i| (-self.players[*i].score, *i))
+            .collect()
+    }
+
+    /// 終了した局の結果から親・本場・場風を進める
+    /// 終局条件を満たした場合はis_game_endを立てる (続行する場合は配牌してstartを呼ぶ)
+    pub fn next_kyoku(&mut self, play_log: &mut PlayLog) -> Result<()> {
+        ensure!(self.is_kyoku_end, GameError::KyokuNotEnded);
+        ensure!(!self.is_game_end, GameError::GameEnded);
+
+        let player_len = self.player_len as usize;
+        let top = self.get_ranking()[0];
+        let is_tobi =
+            self.rule.enable_tobi && self.players[..player_len].iter().any(|p| p.score < 0);
+        // オーラスで親がトップなら和了り止め
+        let is_agariyame = self.rule.enable_agariyame
+            && self.is_all_last()
+            && self.is_renchan
+            && top == self.oya as usize;
+
+        if self.is_renchan || self.is_ryuukyoku {
+            self.tsumobou += 1;
+        } else {
+            self.tsumobou = 0;
+        }
+
+        if !self.is_renchan {
+            self.oya += 1;
+            if self.oya == self.player_len {
+                self.oya = 0;
+                self.bakaze += 1;
+            }
+        }
+
+        // 延長戦は規定の点数に達した時点で終了し、1場だけ行う
+        let last_bakaze = self.get_last_bakaze();
+        let target_score = if self.rule.enable_tonpu {
+            self.rule.nannyu_score
+        } else {
+            self.rule.shanyu_score
+        };
+        let is_over = self.bakaze > last_bakaze
+            && (self.players[top].score >= target_score || self.bakaze > last_bakaze + 1);
+
+        let reason = if is_tobi {
+            "飛び"
+        } else if is_agariyame {
+            "和了り止め"
+        } else if is_over {
+            "規定局数"
+        } else {
+            return Ok(());
+        };
+
+        // 残った供託はトップが受け取る
+        let riichibou = self.take_riichibou();
+        self.players[top].score += riichibou;
+        self.is_game_end = true;
+
+        // 三麻でも4人分の列で記録する
+        let mut scores = [Some(0); 4];
+        for (score, p) in scores.iter_mut().zip(&self.players[..player_len]) {
+            *score = Some(p.score);
+        }
+
+        play_log.append_game_end_log(
+            Uuid::from_bytes_ref(&self.game_id).hyphenated().to_string(),
+            Utc::now().timestamp() as u64,
+            String::from(reason),
+            &scores,
+        );
+
+        Ok(())
+    }
+
+    /// 次にツモするプレイヤー
+    pub fn get_next_teban(&self) -> usize {
+        if self.is_chankan {
+            return self.teban as usize;
+        }
+
+        match self.get_last_sutehai() {
+            Some((who, _)) => (who + 1) % self.player_len as usize,
+            None => self.teban as usize,
+        }
+    }
+
+    /// 捨て牌への応答として行動できるかを確かめる
+    fn check_response(
+        &self,
+        player_index: usize,
+        action_type: ActionType,
+        param: u32,
+    ) -> Result<()> {
+        match action_type {
+            ActionType::ACTION_SYNC => {
+                ensure!(
+                    self.legal_actions(player_index)
+                        .contains(&LegalAction::Pass),
+                    GameError::IllegalAction
+                );
+            }
+            ActionType::ACTION_CHII => {
+                self.check_chii(player_index, param)?;
+            }
+            ActionType::ACTION_PON => {
+                self.check_pon(player_index, param)?;
+            }
+            ActionType::ACTION_KAN => {
+                self.check_minkan(player_index)?;
+            }
+            ActionType::ACTION_RON => {
+                self.check_ron(player_index)?;
+            }
+            _ => return Err(GameError::IllegalAction),
+        }
+
+        Ok(())
+    }
+
+    /// 捨て牌に対する応答(ロン・ポン・カン・チー・見送り)を受け付ける
+    /// 応答できる全員が揃った時点で、ロン > ポン・カン > チーの優先順位で解決する
+    pub fn respond(
+        &mut self,
+        play_log: &mut PlayLog,
+        player_index: usize,
+        action_type: ActionType,
+        param: u32,
+    ) -> Result<()> {
+        ensure!(
+            player_index < self.player_len as usize,
+            GameError::InvalidPlayerIndex
+        );
+
+        let who = match self.get_last_sutehai() {
+            Some((who, _)) => who,
+            None => return Err(GameError::NoSutehai),
+        };
+
+        ensure!(
+            !self.players[player_index].is_responded,
+            GameError::AlreadyResponded
+        );
+
+        // 鳴きに使う2枚は手牌のどの組み合わせでもよい
+        self.check_response(player_index, action_type, param)?;
+
+        let player = &mut self.players[player_index];
+        player.is_responded = true;
+        player.response_type = action_type;
+        player.response_param = param;
+
+        // 見送るしかないプレイヤーの応答は待たない
+        let is_all_responded = (0..self.player_len as usize)
+            .filter(|i| *i != who && !self.players[*i].is_responded)
+            .all(|i| {
+                self.legal_actions(i)
+                    .iter()
+                    .all(|a| *a == LegalAction::Pass)
+            });
+
+        if is_all_responded {
+            self.resolve_responses(play_log)?;
+        }
+
+        Ok(())
+    }
+
+    /// 集まった応答を解決する
+    fn resolve_responses(&mut self, play_log: &mut PlayLog) -> Result<()> {
+        let who = self.teban as usize;
+        let player_len = self.player_len as usize;
+
+        // 下家から順に並べる
+        let responses = (1..player_len)
+            .map(|d| (who + d) % player_len)
+            .filter(|i| self.players[*i].is_responded)
+            .map(|i| {
+                (
+                    i,
+                    self.players[i].response_type,
+                    self.players[i].response_param,
+                )
+            })
+            .collect_vec();
+
+        let rons = responses
+            .iter()
+            .filter(|(_, t, _)| *t == ActionType::ACTION_RON)
+            .map(|(i, _, _)| *i)
+            .collect_vec();
+        if !rons.is_empty() {
+            self.multi_ron(play_log, &rons)?;
+            return Ok(());
+        }
+
+        for priority in [
+            &[ActionType::ACTION_PON, ActionType::ACTION_KAN][..],
+            &[ActionType::ACTION_CHII][..],
+        ] {
+            if let Some((i, t, param)) = responses.iter().find(|(_, t, _)| priority.contains(t)) {
+                return match *t {
+                    ActionType::ACTION_PON => self.pon(play_log, *i, *param),
+                    ActionType::ACTION_KAN => self.minkan(play_log, *i),
+                    _ => self.chii(play_log, *i, *param),
+                };
+            }
+        }
+
+        // 全員見送り (牌山が尽きていれば荒牌流局)
+        if self.is_chankan || self.remain() > 0 {
+            self.tsumo(play_log)
+        } else {
+            self.nagare(play_log)?;
+            Ok(())
+        }
+    }
+
+    /// 鳴き・ロンの対象となる直前の捨て牌を (捨てたプレイヤー, 牌) で返す
+    pub fn get_last_sutehai(&self) -> Option<(usize, PaiT)> {
+        // 加槓した牌は槍槓の対象になる
+        if self.is_chankan && !self.is_kyoku_end {
+            return Some((self.teban as usize, self.chankan_pai.clone()));
+        }
+
+        let teban = &self.players[self.teban as usize];
+
+        // 次の手番がツモする前のみ対象となる
+        if self.is_kyoku_end || teban.is_tsumo || teban.tehai_len % 3 != 1 {
+            return None;
+        }
+
+        // 手番は応答が解決するまで捨てたプレイヤーのまま
+        let who = self.teban as usize;
+        let player = &self.players[who];
+
+        if player.kawahai_len == 0 {
+            return None;
+        }
+
+        let pai = &player.kawahai[player.kawahai_len as usize - 1];
+
+        if pai.is_nakare {
+            return None;
+        }
+
+        Some((who, pai.clone()))
+    }
+
+    /// 鳴いた牌が誰から出たかを表すフラグ
+    fn get_naki_flag(&self, player_index: usize, who: usize) -> MentsuFlag {
+        let player_len = self.player_len as usize;
+        let diff = (player_index + player_len - who) % player_len;
+
+        if diff == 1 {
+            MentsuFlag::FLAG_KAMICHA
+        } else if diff == player_len - 1 {
+            MentsuFlag::FLAG_SIMOCHA
+        } else {
+            MentsuFlag::FLAG_TOIMEN
+        }
+    }
+
+    /// 手牌の待ち牌を返す (テンパイでなければ空)
+    pub fn get_player_machi(&self, who: usize) -> Vec<u8> {
+        let player = &self.players[who];
+
+        if player.tehai_len % 3 != 1 {
+            return vec![];
+        }
+
+        let tehai = player.tehai[..player.tehai_len as usize].to_vec();
+        PaiState::from(&tehai).get_machi(player.mentsu_len as usize)
+    }
+
+    /// 鳴いた直後に捨てられない牌番号 (喰い替えありのルールでは空)
+    pub fn get_kuikae_pai_nums(&self, who: usize) -> Vec<u8> {
+        let player = &self.players[who];
+
+        if self.rule.enable_kuinaoshi
+            || player.is_tsumo
+            || player.tehai_len % 3 != 2
+            || player.mentsu_len == 0
+        {
+            return vec![];
+        }
+
+        let mentsu = &player.mentsu[player.mentsu_len as usize - 1];
+        let naki_pai = mentsu.pai_list[..3]
+            .iter()
+            .find(|p| p.flag != MentsuFlag::FLAG_NONE);
+
+        match naki_pai {
+            Some(pai) => kuikae_pai_nums(
+                pai.pai_num,
+                &[
+                    mentsu.pai_list[0].pai_num,
+                    mentsu.pai_list[1].pai_num,
+                    mentsu.pai_list[2].pai_num,
+                ],
+            ),
+            None => vec![],
+        }
+    }
+
+    /// 鳴いた後に喰い替えにならない打牌が残るかどうか
+    fn can_discard_after_naki(
+        &self,
+        player_index: usize,
+        pai: &PaiT,
+        indices: &[usize; 2],
+    ) -> bool {
+        if self.rule.enable_kuinaoshi {
+            return true;
+        }
+
+        let player = &self.players[player_index];
+        let mut nums = [
+            pai.pai_num,
+            player.tehai[indices[0]].pai_num,
+            player.tehai[indices[1]].pai_num,
+        ];
+        nums.sort_unstable();
+        let kuikae = kuikae_pai_nums(pai.pai_num, &nums);
+
+        player.tehai[..player.tehai_len as usize]
+            .iter()
+            .enumerate()
+            .any(|(i, p)| !indices.contains(&i) && !kuikae.contains(&p.pai_num))
+    }
+
+    /// フリテンかどうか
+    /// 自分の捨て牌に待ち牌がある場合と、同巡内・リーチ後に和了牌を見逃した場合
+    pub fn is_furiten(&self, who: usize) -> bool {
+        let player = &self.players[who];
+
+        if player.is_doujun_furiten || player.is_riichi_furiten {
+            return true;
+        }
+
+        let machi = self.get_player_machi(who);
+
+        player.kawahai[..player.kawahai_len as usize]
+            .iter()
+            .any(|p| machi.contains(&p.pai_num))
+    }
+
+    /// 直前の捨て牌が見逃されたときに、和了できたプレイヤーをフリテンにする
+    fn update_furiten_on_pass(&mut self, who: usize, pai: &PaiT, caller: Option<usize>) {
+        for idx in 0..self.player_len as usize {
+            if idx == who || Some(idx) == caller {
+                continue;
+            }
+
+            if self.get_player_machi(idx).contains(&pai.pai_num) {
+                let player = &mut self.players[idx];
+
+                if player.is_riichi {
+                    player.is_riichi_furiten = true;
+                } else {
+                    player.is_doujun_furiten = true;
+                }
+            }
+        }
+    }
+
+    /// 鳴きが発生したときの共通処理
+    fn naki(&mut self, player_index: usize, who: usize) {
+        let kawahai_len = self.players[who].kawahai_len as usize;
+        let pai = self.players[who].kawahai[kawahai_len - 1].clone();
+        self.update_furiten_on_pass(who, &pai, Some(player_index));
+
+        self.players[who].kawahai[kawahai_len - 1].is_nakare = true;
+
+        // 一発は鳴きで消える
+        for player in self.players.iter_mut() {
+            player.is_ippatsu = false;
+        }
+
+        self.teban = player_index as u32;
+    }
+
+    /// チーできるかを確かめ、(捨てたプレイヤー, 鳴く牌, 手牌から出すインデックス)を返す
+    fn check_chii(&self, player_index: usize, param: u32) -> Result<(usize, PaiT, [usize; 2])> {
+        let (who, pai) = match self.get_last_sutehai() {
+            Some(x) => x,
+            None => return Err(GameError::NoSutehai),
+        };
+
+        // 三麻ではチーできない
+        ensure!(!self.is_sanma(), GameError::IllegalAction);
+        // 加槓した牌は槍槓しかできない
+        ensure!(!self.is_chankan, GameError::IllegalAction);
+        ensure!(
+            who != player_index && (who + 1) % self.player_len as usize == player_index,
+            GameError::IllegalAction
+        );
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        let indices = unpack_naki_param(param);
+        let player = &self.players[player_index];
+
+        ensure!(!player.is_riichi, GameError::InRiichi);
+        ensure!(player.mentsu_len < 4, GameError::NakiLimit);
+        ensure!(
+            indices[0] != indices[1] && indices.iter().all(|i| *i < player.tehai_len as usize),
+            GameError::InvalidTileIndex
+        );
+
+        let mut nums = vec![pai.pai_num];
+        nums.extend(indices.iter().map(|i| player.tehai[*i].pai_num));
+        nums.sort_unstable();
+
+        ensure!(
+            nums[2] < 27
+                && nums[0] / 9 == nums[2] / 9
+                && nums[0] + 1 == nums[1]
+                && nums[1] + 1 == nums[2],
+            GameError::InvalidMentsu
+        );
+        ensure!(
+            self.can_discard_after_naki(player_index, &pai, &indices),
+            GameError::Kuikae
+        );
+
+        Ok((who, pai, indices))
+    }
+
+    /// チー
+    /// paramには手牌から出す2枚のインデックスを下位バイトから順に指定する
+    pub fn chii(&mut self, play_log: &mut PlayLog, player_index: usize, param: u32) -> Result<()> {
+        let (who, pai, indices) = self.check_chii(player_index, param)?;
+        let player = &mut self.players[player_index];
+        let tehai = remove_tehai(player, &indices);
+        push_mentsu(
+            player,
+            &tehai,
+            Some((&pai, MentsuFlag::FLAG_KAMICHA)),
+            MentsuType::TYPE_SHUNTSU,
+        );
+
+        self.naki(player_index, who);
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("chii"),
+            pai.get_pai_id(),
+        );
+        self.seq += 1;
+
+        Ok(())
+    }
+
+    /// 大三元・大四喜が確定する牌を鳴かせたプレイヤーを責任払いにする
+    fn update_pao(&mut self, player_index: usize, who: usize, pai_num: u8) {
+        if !self.rule.enable_pao {
+            return;
+        }
+
+        let (range, needed) = match pai_num {
+            31..=33 => (31..=33, 3),
+            27..=30 => (27..=30, 4),
+            _ => return,
+        };
+
+        let player = &mut self.players[player_index];
+        let count = player.mentsu[..player.mentsu_len as usize]
+            .iter()
+            .filter(|m| {
+                m.mentsu_type != MentsuType::TYPE_SHUNTSU && range.contains(&m.pai_list[0].pai_num)
+            })
+            .count();
+
+        if count == needed {
+            player.is_pao = true;
+            player.pao_player = who as u32;
+        }
+    }
+
+    /// ポンできるかを確かめ、(捨てたプレイヤー, 鳴く牌, 手牌から出すインデックス)を返す
+    fn check_pon(&self, player_index: usize, param: u32) -> Result<(usize, PaiT, [usize; 2])> {
+        let (who, pai) = match self.get_last_sutehai() {
+            Some(x) => x,
+            None => return Err(GameError::NoSutehai),
+        };
+
+        ensure!(who != player_index, GameError::OwnSutehai);
+        ensure!(!self.is_chankan, GameError::IllegalAction);
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        let indices = unpack_naki_param(param);
+        let player = &self.players[player_index];
+
+        ensure!(!player.is_riichi, GameError::InRiichi);
+        ensure!(player.mentsu_len < 4, GameError::NakiLimit);
+        ensure!(
+            indices[0] != indices[1] && indices.iter().all(|i| *i < player.tehai_len as usize),
+            GameError::InvalidTileIndex
+        );
+        ensure!(
+            indices
+                .iter()
+                .all(|i| player.tehai[*i].pai_num == pai.pai_num),
+            GameError::InvalidMentsu
+        );
+        ensure!(
+            self.can_discard_after_naki(player_index, &pai, &indices),
+            GameError::Kuikae
+        );
+
+        Ok((who, pai, indices))
+    }
+
+    /// ポン
+    /// paramには手牌から出す2枚のインデックスを下位バイトから順に指定する
+    /// 間のプレイヤーのツモは飛ばされ、鳴いたプレイヤーはツモせずに捨て牌する
+    pub fn pon(&mut self, play_log: &mut PlayLog, player_index: usize, param: u32) -> Result<()> {
+        let (who, pai, indices) = self.check_pon(player_index, param)?;
+        let flag = self.get_naki_flag(player_index, who);
+        let player = &mut self.players[player_index];
+        let tehai = remove_tehai(player, &indices);
+        push_mentsu(player, &tehai, Some((&pai, flag)), MentsuType::TYPE_KOUTSU);
+
+        self.naki(player_index, who);
+        self.update_pao(player_index, who, pai.pai_num);
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("pon"),
+            pai.get_pai_id(),
+        );
+        self.seq += 1;
+
+        Ok(())
+    }
+
+    /// 自分の手番で槓できる牌を取り出す
+    /// indexに13を指定した場合はツモ牌
+    fn get_kan_target(&self, player_index: usize, index: usize) -> Result<PaiT> {
+        ensure!(player_index == self.teban as usize, GameError::NotYourTurn);
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        let player = &self.players[player_index];
+
+        ensure!(player.is_tsumo, GameError::InvalidState);
+        ensure!(self.get_kan_count() < 4, GameError::KanLimit);
+
+        if index == TSUMOHAI_INDEX {
+            Ok(player.tsumohai.clone())
+        } else {
+            ensure!(
+                index < player.tehai_len as usize,
+                GameError::InvalidTileIndex
+            );
+            Ok(player.tehai[index].clone())
+        }
+    }
+
+    /// 暗槓できるかを確かめ、槓する牌を返す
+    fn check_ankan(&self, player_index: usize, index: usize) -> Result<PaiT> {
+        let target = self.get_kan_target(player_index, index)?;
+        let player = &self.players[player_index];
+        let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+        tehai.push(player.tsumohai.clone());
+
+        ensure!(
+            tehai.iter().filter(|p| p.pai_num == target.pai_num).count() == 4,
+            GameError::InvalidMentsu
+        );
+
+        if player.is_riichi {
+            ensure!(self.rule.kan_in_riichi_type != 0, GameError::InRiichi);
+            ensure!(
+                player.tsumohai.pai_num == target.pai_num,
+                GameError::InRiichi
+            );
+
+            // 待ちが変わる暗槓はできない
+            let before = &tehai[..tehai.len() - 1];
+            let after: Vec<PaiT> = before
+                .iter()
+                .filter(|p| p.pai_num != target.pai_num)
+                .cloned()
+                .collect();
+            let n_fulo = player.mentsu_len as usize;
+
+            ensure!(
+                PaiState::from(before).get_machi(n_fulo)
+                    == PaiState::from(&after).get_machi(n_fulo + 1),
+                GameError::InRiichi
+            );
+        }
+
+        Ok(target)
+    }
+
+    /// 暗槓
+    /// indexには槓する牌の手牌のインデックス(13はツモ牌)を指定する
+    pub fn ankan(
+        &mut self,
+        play_log: &mut PlayLog,
+        player_index: usize,
+        index: usize,
+    ) -> Result<()> {
+        let target = self.check_ankan(player_index, index)?;
+        let player = &self.players[player_index];
+        let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+        tehai.push(player.tsumohai.clone());
+
+        let (kan, rest): (Vec<PaiT>, Vec<PaiT>) =
+            tehai.into_iter().partition(|p| p.pai_num == target.pai_num);
+        let player = &mut self.players[player_index];
+
+        player.tehai = Default::default();
+        for (i, item) in rest.iter().sorted().enumerate() {
+            player.tehai[i] = item.clone();
+        }
+        player.tehai_len = rest.len() as u32;
+        player.tsumohai = Default::default();
+        player.is_tsumo = false;
+
+        push_mentsu(player, &kan, None, MentsuType::TYPE_ANKAN);
+
+        for player in self.players.iter_mut() {
+            player.is_ippatsu = false;
+        }
+
+        // 暗槓の槓ドラは即めくる
+        self.update_kan_dora();
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("ankan"),
+            target.get_pai_id(),
+        );
+        self.seq += 1;
+
+        self.rinshan_tsumo(play_log, player_index)
+    }
+
+    /// 加槓できるかを確かめ、(槓する牌, 加える刻子のインデックス)を返す
+    fn check_kakan(&self, player_index: usize, index: usize) -> Result<(PaiT, usize)> {
+        let target = self.get_kan_target(player_index, index)?;
+        let player = &self.players[player_index];
+        let mentsu_index = player.mentsu[..player.mentsu_len as usize]
+            .iter()
+            .position(|m| {
+                m.mentsu_type == MentsuType::TYPE_KOUTSU && m.pai_list[0].pai_num == target.pai_num
+            });
+
+        match mentsu_index {
+            Some(x) => Ok((target, x)),
+            None => Err(GameError::InvalidMentsu),
+        }
+    }
+
+    /// 加槓
+    /// indexにはポンした牌と同じ牌の手牌のインデックス(13はツモ牌)を指定する
+    pub fn kakan(
+        &mut self,
+        play_log: &mut PlayLog,
+        player_index: usize,
+        index: usize,
+    ) -> Result<()> {
+        let (target, mentsu_index) = self.check_kakan(player_index, index)?;
+        let player = &mut self.players[player_index];
+
+        if index != TSUMOHAI_INDEX {
+            let tsumohai = player.tsumohai.clone();
+            remove_tehai(player, &[index]);
+            player.tehai[player.tehai_len as usize] = tsumohai;
+            player.tehai_len += 1;
+            player.tehai[..player.tehai_len as usize].sort_unstable();
+        }
+        player.tsumohai = Default::default();
+        player.is_tsumo = false;
+
+        let mentsu = &mut player.mentsu[mentsu_index];
+        mentsu.pai_list[3] = to_mentsu_pai(&target, MentsuFlag::FLAG_NONE);
+        mentsu.pai_len = 4;
+        mentsu.mentsu_type = MentsuType::TYPE_MINKAN;
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("kakan"),
+            target.get_pai_id(),
+        );
+        self.seq += 1;
+
+        // 槍槓できるプレイヤーがいれば応答を待つ
+        self.is_chankan = true;
+        self.chankan_pai = target;
+        for player in self.players.iter_mut() {
+            player.is_responded = false;
+        }
+        let can_chankan = (0..self.player_len as usize).any(|i| {
+            i != player_index
+                && !self.is_furiten(i)
+                && self
+                    .evaluate_agari(i, &self.chankan_pai, AgariType::Ron)
+                    .is_ok()
+        });
+        if can_chankan {
+            return Ok(());
+        }
+        self.is_chankan = false;
+
+        self.kan_rinshan_tsumo(play_log, player_index)
+    }
+
+    /// 加槓の後、一発を消して嶺上牌をツモする
+    fn kan_rinshan_tsumo(&mut self, play_log: &mut PlayLog, player_index: usize) -> Result<()> {
+        for player in self.players.iter_mut() {
+            player.is_ippatsu = false;
+        }
+
+        if self.rule.enable_kansaki {
+            self.update_kan_dora();
+        }
+
+        self.rinshan_tsumo(play_log, player_index)
+    }
+
+    /// 大明槓できるかを確かめ、(捨てたプレイヤー, 鳴く牌, 手牌から出すインデックス)を返す
+    fn check_minkan(&self, player_index: usize) -> Result<(usize, PaiT, Vec<usize>)> {
+        let (who, pai) = match self.get_last_sutehai() {
+            Some(x) => x,
+            None => return Err(GameError::NoSutehai),
+        };
+
+        ensure!(who != player_index, GameError::OwnSutehai);
+        ensure!(!self.is_chankan, GameError::IllegalAction);
+        ensure!(self.get_kan_count() < 4, GameError::KanLimit);
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        let player = &self.players[player_index];
+
+        ensure!(!player.is_riichi, GameError::InRiichi);
+        ensure!(player.mentsu_len < 4, GameError::NakiLimit);
+
+        let indices: Vec<usize> = player.tehai[..player.tehai_len as usize]
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.pai_num == pai.pai_num)
+            .map(|(i, _)| i)
+            .collect();
+
+        ensure!(indices.len() == 3, GameError::InvalidMentsu);
+
+        Ok((who, pai, indices))
+    }
+
+    /// 大明槓
+    pub fn minkan(&mut self, play_log: &mut PlayLog, player_index: usize) -> Result<()> {
+        let (who, pai, indices) = self.check_minkan(player_index)?;
+        let flag = self.get_naki_flag(player_index, who);
+        let player = &mut self.players[player_index];
+        let tehai = remove_tehai(player, &indices);
+        push_mentsu(player, &tehai, Some((&pai, flag)), MentsuType::TYPE_MINKAN);
+
+        self.naki(player_index, who);
+        self.update_pao(player_index, who, pai.pai_num);
+
+        if self.rule.enable_kansaki {
+            self.update_kan_dora();
+        }
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("minkan"),
+            pai.get_pai_id(),
+        );
+        self.seq += 1;
+
+        self.rinshan_tsumo(play_log, player_index)
+    }
+
+    /// 北抜きできるかを確かめ、抜く牌を返す
+    fn check_kita(&self, player_index: usize, index: usize) -> Result<PaiT> {
+        ensure!(self.is_sanma(), GameError::IllegalAction);
+        ensure!(player_index == self.teban as usize, GameError::NotYourTurn);
+        ensure!(self.remain() > 0, GameError::WallExhausted);
+
+        let player = &self.players[player_index];
+
+        ensure!(player.is_tsumo, GameError::InvalidState);
+
+        let pai = match index {
+            TSUMOHAI_INDEX => player.tsumohai.clone(),
+            _ => {
+                ensure!(
+                    index < player.tehai_len as usize,
+                    GameError::InvalidTileIndex
+                );
+                player.tehai[index].clone()
+            }
+        };
+
+        ensure!(pai.pai_num == PEI, GameError::IllegalAction);
+        ensure!(
+            !(player.is_riichi && index != TSUMOHAI_INDEX),
+            GameError::InRiichi
+        );
+
+        Ok(pai)
+    }
+
+    /// 北抜き
+    /// indexには抜く北の手牌のインデックス(13はツモ牌)を指定する
+    pub fn kita(
+        &mut self,
+        play_log: &mut PlayLog,
+        player_index: usize,
+        index: usize,
+    ) -> Result<()> {
+        let pai = self.check_kita(player_index, index)?;
+        let player = &mut self.players[player_index];
+
+        if index != TSUMOHAI_INDEX {
+            let mut tehai: Vec<PaiT> = player.tehai[..player.tehai_len as usize].to_vec();
+            tehai.remove(index);
+            tehai.push(player.tsumohai.clone());
+            tehai.sort_unstable();
+            for (i, item) in tehai.into_iter().enumerate() {
+                player.tehai[i] = item;
+            }
+        }
+
+        player.tsumohai = Default::default();
+        player.is_tsumo = false;
+        player.nukidora += 1;
+
+        play_log.append_actions_log(
+            self.kyoku_id,
+            player_index as i32,
+            self.seq as i32,
+            String::from("kita"),
+            pai.get_pai_id(),
+        );
+        self.seq += 1;
+
+        self.rinshan_tsumo(play_log, player_index)
+    }
+
+    /// 行動をエンジンが受け付けるかを返す
+    fn is_legal(&self, player_index: usize, action: &LegalAction) -> bool {
+        let is_teban = player_index == self.teban as usize;
+
+        match *action {
+            LegalAction::Sutehai(index) => is_teban && self.check_sutehai(index, false).is_ok(),
+            LegalAction::Riichi(index) => is_teban && self.check_sutehai(index, true).is_ok(),
+            LegalAction::Tsumo => is_teban && self.check_tsumo_agari().is_ok(),
+            LegalAction::Ron => self.check_ron(player_index).is_ok(),
+            LegalAction::Chii(param) => self.check_chii(player_index, param).is_ok(),
+            LegalAction::Pon(param) => self.check_pon(player_index, param).is_ok(),
+            LegalAction::Ankan(index) => self.check_ankan(player_index, index).is_ok(),
+            LegalAction::Kakan(index) => self.check_kakan(player_index, index).is_ok(),
+            LegalAction::Minkan => self.check_minkan(player_index).is_ok(),
+            LegalAction::Nagashi => self.can_kyuushu(player_index),
+            LegalAction::Kita(index) => self.check_kita(player_index, index).is_ok(),
+            LegalAction::Pass => true,
+        }
+    }
+
+    /// 選択できる行動の一覧
+    pub fn legal_actions(&self, player_index: usize) -> Vec<LegalAction> {
+        let mut candidates = Vec::new();
+
+        if self.is_kyoku_end || player_index >= self.player_len as usize {
+            return candidates;
+        }
+
+        let player = &self.players[player_index];
+        let tehai = &player.tehai[..player.tehai_len as usize];
+        let is_teban = player_index == self.teban as usize;
+        let is_discard_phase = is_teban && (player.is_tsumo || player.tehai_len % 3 == 2);
+
+        if is_discard_phase {
+            let mut indices = (0..tehai.len()).collect_vec();
+            if player.is_tsumo {
+                indices.push(TSUMOHAI_INDEX);
+            }
+
+            candidates.extend(indices.iter().map(|i| LegalAction::Sutehai(*i)));
+            candidates.extend(indices.iter().map(|i| LegalAction::Riichi(*i)));
+            candidates.push(LegalAction::Tsumo);
+            candidates.push(LegalAction::Nagashi);
+
+            // 手牌とツモ牌の北をそれぞれ1つずつ候補にする
+            candidates.extend(
+                indices
+                    .iter()
+                    .filter(|i| match **i {
+                        TSUMOHAI_INDEX => player.tsumohai.pai_num == PEI,
+                        _ => tehai[**i].pai_num == PEI,
+                    })
+                    .unique_by(|i| **i == TSUMOHAI_INDEX)
+                    .map(|i| LegalAction::Kita(*i)),
+            );
+
+            // 同じ牌の槓は1つにまとめる
+            for i in indices.into_iter().unique_by(|i| match *i {
+                TSUMOHAI_INDEX => player.tsumohai.pai_num,
+                _ => tehai[*i].pai_num,
+            }) {
+                let pai_num = match i {
+                    TSUMOHAI_INDEX => player.tsumohai.pai_num,
+                    _ => tehai[i].pai_num,
+                };
+                let is_kakan = player.mentsu[..player.mentsu_len as usize].iter().any(|m| {
+                    m.mentsu_type == MentsuType::TYPE_KOUTSU && m.pai_list[0].pai_num == pai_num
+                });
+
+                candidates.push(if is_kakan {
+                    LegalAction::Kakan(i)
+                } else {
+                    LegalAction::Ankan(i)
+                });
+            }
+        } else if let Some((who, pai)) = self.get_last_sutehai() {
+            if who == player_index {
+                return vec![];
+            }
+
+            candidates.push(LegalAction::Ron);
+            candidates.push(LegalAction::Minkan);
+
+            // 捨て牌と組み合わせられる2枚を牌の種類ごとに列挙する (赤牌は別の候補にする)
+            let pairs = (0..tehai.len()).tuple_combinations().unique_by(|(i, j)| {
+                (
+                    (tehai[*i].pai_num, tehai[*i].is_aka),
+                    (tehai[*j].pai_num, tehai[*j].is_aka),
+                )
+            });
+            for (i, j) in pairs {
+                let param = (i | j << 8) as u32;
+                let nums = [tehai[i].pai_num, tehai[j].pai_num];
+
+                if nums.iter().all(|n| *n == pai.pai_num) {
+                    candidates.push(LegalAction::Pon(param));
+                } else if nums
+                    .iter()
+                    .all(|n| n / 9 == pai.pai_num / 9 && n.abs_diff(pai.pai_num) <= 2)
+                {
+                    candidates.push(LegalAction::Chii(param));
+                }
+            }
+        }
+
+        let mut actions = candidates
+            .into_iter()
+            .filter(|a| self.is_legal(player_index, a))
+            .collect_vec();
+
+        // 次にツモするプレイヤー、または鳴き・ロンができる場合は見送れる
+        if !is_discard_phase && (player_index == self.get_next_teban() || !actions.is_empty()) {
+            actions.push(LegalAction::Pass);
+        }
+
+        actions
+    }
+
+    pub fn action(
+        &mut self,
+        play_log: &mut PlayLog,
+        action_type: ActionType,
+        player_index: usize,
+        param: u32,
+    ) -> Result<()> {
+        ensure!(
+            player_index < self.player_len as usize,
+            GameError::InvalidPlayerIndex
+        );
+        ensure!(!self.is_kyoku_end, GameError::KyokuEnded);
+
+        // 捨て牌への鳴き・ロン・見送りは応答として受け付け、揃ってから優先順位で解決する
+        if self.get_last_sutehai().is_some() {
+            match action_type {
+                ActionType::ACTION_SYNC if self.legal_actions(player_index).is_empty() => {
+                    return Ok(());
+                }
+                ActionType::ACTION_SYNC
+                | ActionType::ACTION_CHII
+                | ActionType::ACTION_PON
+                | ActionType::ACTION_KAN
+                | ActionType::ACTION_RON => {
+                    return self.respond(play_log, player_index, action_type, param);
+                }
+                _ => {}
+            }
+        }
+
+        match action_type {
+            ActionType::ACTION_RIICHI => {
+                if player_index == self.teban as usize {
+                    self.sutehai(play_log, param as usize, true)
+                } else {
+                    Err(GameError::NotYourTurn)
+                }
+            }
+            ActionType::ACTION_SYNC => {
+                if player_index == self.get_next_teban() {
+                    self.tsumo(play_log)
+                } else {
+                    Ok(())
+                }
+            }
+            ActionType::ACTION_SUTEHAI => {
+                if player_index == self.teban as usize {
+                    self.sutehai(play_log, param as usize, false)
+                } else {
+                    Err(GameError::NotYourTurn)
+                }
+            }
+            ActionType::ACTION_CHII => self.chii(play_log, player_index, param),
+            ActionType::ACTION_PON => self.pon(play_log, player_index, param),
+            ActionType::ACTION_KAN => {
+                let player = &self.players[player_index];
+
+                if player_index == self.teban as usize && player.is_tsumo {
+                    // ポンした刻子があれば加槓、なければ暗槓
+                    let target = self.get_kan_target(player_index, param as usize)?;
+                    let is_kakan = player.mentsu[..player.mentsu_len as usize].iter().any(|m| {
+                        m.mentsu_type == MentsuType::TYPE_KOUTSU
+                            && m.pai_list[0].pai_num == target.pai_num
+                    });
+
+                    if is_kakan {
+                        self.kakan(play_log, player_index, param as usize)
+                    } else {
+                        self.ankan(play_log, player_index, param as usize)
+                    }
+                } else {
+                    self.minkan(play_log, player_index)
+                }
+            }
+            ActionType::ACTION_TSUMO => {
+                if player_index == self.teban as usize {
+                    self.tsumo_agari(play_log)?;
+                    Ok(())
+                } else {
+                    Err(GameError::NotYourTurn)
+                }
+            }
+            ActionType::ACTION_NAGASHI => self.kyuushu(play_log, player_index),
+            ActionType::ACTION_RON => {
+                self.ron(play_log, player_index)?;
+                Ok(())
             }
-            ActionType::ACTION_NAGASHI => todo!(),
-            _ => todo!(),
+            ActionType::ACTION_KITA => self.kita(play_log, player_index, param as usize),
+            _ => Err(GameError::UnknownAction),
         }
     }
 
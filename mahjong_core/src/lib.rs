@@ -1,8 +1,12 @@
 pub mod fbs_utils;
 pub mod game_process;
+#[allow(clippy::all)]
 pub mod mahjong_generated;
 pub mod shanten;
 pub mod agari;
 pub mod play_log;
+pub mod payment;
+pub mod error;
+pub mod fair_shuffle;
 #[cfg(feature = "load-pailist")]
 pub mod load_pailist;
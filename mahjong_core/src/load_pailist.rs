@@ -35,6 +35,7 @@ fn str_to_pais<T: AsRef<str>>(input: T) -> Vec<PaiT> {
                     pai_num: suit * 9 + c.to_digit(10).unwrap() as u8 - 1,
                     id: 0,
                     is_nakare: false,
+                    is_aka: false,
                     is_riichi: false,
                     is_tsumogiri: false
                 };
@@ -158,6 +159,7 @@ impl ParquetAgari {
                         pai_num: (dora >> 2) as u8,
                         id: (dora & 3) as u8,
                         is_nakare: false,
+                        is_aka: false,
                         is_riichi: false,
                         is_tsumogiri: false
                     }
@@ -172,6 +174,7 @@ impl ParquetAgari {
                     pai_num: (cell >> 2) as u8,
                     id: (cell & 3) as u8,
                     is_nakare: false,
+                    is_aka: false,
                     is_riichi: false,
                     is_tsumogiri: false
                 };
@@ -189,6 +192,7 @@ impl ParquetAgari {
                         pai_num: (dora >> 2) as u8,
                         id: (dora & 3) as u8,
                         is_nakare: false,
+                        is_aka: false,
                         is_riichi: false,
                         is_tsumogiri: false
                     }
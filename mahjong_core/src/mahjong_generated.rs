@@ -21,10 +21,10 @@ pub mod open_mahjong {
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_ACTION_TYPE: u32 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_ACTION_TYPE: u32 = 7;
+pub const ENUM_MAX_ACTION_TYPE: u32 = 9;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_ACTION_TYPE: [ActionType; 8] = [
+pub const ENUM_VALUES_ACTION_TYPE: [ActionType; 10] = [
   ActionType::ACTION_SYNC,
   ActionType::ACTION_SUTEHAI,
   ActionType::ACTION_CHII,
@@ -33,6 +33,8 @@ pub const ENUM_VALUES_ACTION_TYPE: [ActionType; 8] = [
   ActionType::ACTION_TSUMO,
   ActionType::ACTION_NAGASHI,
   ActionType::ACTION_RIICHI,
+  ActionType::ACTION_RON,
+  ActionType::ACTION_KITA,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -48,9 +50,11 @@ impl ActionType {
   pub const ACTION_TSUMO: Self = Self(5);
   pub const ACTION_NAGASHI: Self = Self(6);
   pub const ACTION_RIICHI: Self = Self(7);
+  pub const ACTION_RON: Self = Self(8);
+  pub const ACTION_KITA: Self = Self(9);
 
   pub const ENUM_MIN: u32 = 0;
-  pub const ENUM_MAX: u32 = 7;
+  pub const ENUM_MAX: u32 = 9;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::ACTION_SYNC,
     Self::ACTION_SUTEHAI,
@@ -60,6 +64,8 @@ impl ActionType {
     Self::ACTION_TSUMO,
     Self::ACTION_NAGASHI,
     Self::ACTION_RIICHI,
+    Self::ACTION_RON,
+    Self::ACTION_KITA,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -72,6 +78,8 @@ impl ActionType {
       Self::ACTION_TSUMO => Some("ACTION_TSUMO"),
       Self::ACTION_NAGASHI => Some("ACTION_NAGASHI"),
       Self::ACTION_RIICHI => Some("ACTION_RIICHI"),
+      Self::ACTION_RON => Some("ACTION_RON"),
+      Self::ACTION_KITA => Some("ACTION_KITA"),
       _ => None,
     }
   }
@@ -558,10 +566,10 @@ impl FixedStringT {
 // struct Pai, aligned to 1
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct Pai(pub [u8; 5]);
+pub struct Pai(pub [u8; 6]);
 impl Default for Pai { 
   fn default() -> Self { 
-    Self([0; 5])
+    Self([0; 6])
   }
 }
 impl core::fmt::Debug for Pai {
@@ -572,6 +580,7 @@ impl core::fmt::Debug for Pai {
       .field("is_tsumogiri", &self.is_tsumogiri())
       .field("is_riichi", &self.is_riichi())
       .field("is_nakare", &self.is_nakare())
+      .field("is_aka", &self.is_aka())
       .finish()
   }
 }
@@ -618,13 +627,15 @@ impl<'a> Pai {
     is_tsumogiri: bool,
     is_riichi: bool,
     is_nakare: bool,
+    is_aka: bool,
   ) -> Self {
-    let mut s = Self([0; 5]);
+    let mut s = Self([0; 6]);
     s.set_pai_num(pai_num);
     s.set_id(id);
     s.set_is_tsumogiri(is_tsumogiri);
     s.set_is_riichi(is_riichi);
     s.set_is_nakare(is_nakare);
+    s.set_is_aka(is_aka);
     s
   }
 
@@ -773,6 +784,35 @@ impl<'a> Pai {
     }
   }
 
+  pub fn is_aka(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[5..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_aka(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[5..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
   pub fn unpack(&self) -> PaiT {
     PaiT {
       pai_num: self.pai_num(),
@@ -780,6 +820,7 @@ impl<'a> Pai {
       is_tsumogiri: self.is_tsumogiri(),
       is_riichi: self.is_riichi(),
       is_nakare: self.is_nakare(),
+      is_aka: self.is_aka(),
     }
   }
 }
@@ -791,6 +832,7 @@ pub struct PaiT {
   pub is_tsumogiri: bool,
   pub is_riichi: bool,
   pub is_nakare: bool,
+  pub is_aka: bool,
 }
 impl PaiT {
   pub fn pack(&self) -> Pai {
@@ -800,6 +842,7 @@ impl PaiT {
       self.is_tsumogiri,
       self.is_riichi,
       self.is_nakare,
+      self.is_aka,
     )
   }
 }
@@ -807,10 +850,10 @@ impl PaiT {
 // struct Taku, aligned to 4
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct Taku(pub [u8; 684]);
+pub struct Taku(pub [u8; 820]);
 impl Default for Taku { 
   fn default() -> Self { 
-    Self([0; 684])
+    Self([0; 820])
   }
 }
 impl core::fmt::Debug for Taku {
@@ -870,7 +913,7 @@ impl<'a> Taku {
     n5: &[Pai; 8],
     length: u32,
   ) -> Self {
-    let mut s = Self([0; 684]);
+    let mut s = Self([0; 820]);
     s.set_n1(n1);
     s.set_n2(n2);
     s.set_n3(n3);
@@ -895,7 +938,7 @@ impl<'a> Taku {
       core::ptr::copy(
         x.as_ptr() as *const u8,
         self.0.as_mut_ptr().add(0),
-        160,
+        192,
       );
     }
   }
@@ -904,7 +947,7 @@ impl<'a> Taku {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 160) }
+    unsafe { flatbuffers::Array::follow(&self.0, 192) }
   }
 
   pub fn set_n2(&mut self, x: &[Pai; 32]) {
@@ -914,8 +957,8 @@ impl<'a> Taku {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(160),
-        160,
+        self.0.as_mut_ptr().add(192),
+        192,
       );
     }
   }
@@ -924,7 +967,7 @@ impl<'a> Taku {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 320) }
+    unsafe { flatbuffers::Array::follow(&self.0, 384) }
   }
 
   pub fn set_n3(&mut self, x: &[Pai; 32]) {
@@ -934,8 +977,8 @@ impl<'a> Taku {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(320),
-        160,
+        self.0.as_mut_ptr().add(384),
+        192,
       );
     }
   }
@@ -944,7 +987,7 @@ impl<'a> Taku {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 480) }
+    unsafe { flatbuffers::Array::follow(&self.0, 576) }
   }
 
   pub fn set_n4(&mut self, x: &[Pai; 32]) {
@@ -954,8 +997,8 @@ impl<'a> Taku {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(480),
-        160,
+        self.0.as_mut_ptr().add(576),
+        192,
       );
     }
   }
@@ -964,7 +1007,7 @@ impl<'a> Taku {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 640) }
+    unsafe { flatbuffers::Array::follow(&self.0, 768) }
   }
 
   pub fn set_n5(&mut self, x: &[Pai; 8]) {
@@ -974,8 +1017,8 @@ impl<'a> Taku {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(640),
-        40,
+        self.0.as_mut_ptr().add(768),
+        48,
       );
     }
   }
@@ -987,7 +1030,7 @@ impl<'a> Taku {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[680..].as_ptr(),
+        self.0[816..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -1003,7 +1046,7 @@ impl<'a> Taku {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[680..].as_mut_ptr(),
+        self.0[816..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -1395,10 +1438,10 @@ impl MentsuT {
 // struct Player, aligned to 4
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct Player(pub [u8; 532]);
+pub struct Player(pub [u8; 592]);
 impl Default for Player { 
   fn default() -> Self { 
-    Self([0; 532])
+    Self([0; 592])
   }
 }
 impl core::fmt::Debug for Player {
@@ -1415,8 +1458,18 @@ impl core::fmt::Debug for Player {
       .field("is_tsumo", &self.is_tsumo())
       .field("is_riichi", &self.is_riichi())
       .field("is_ippatsu", &self.is_ippatsu())
+      .field("is_doujun_furiten", &self.is_doujun_furiten())
+      .field("is_riichi_furiten", &self.is_riichi_furiten())
+      .field("is_responded", &self.is_responded())
+      .field("response_type", &self.response_type())
+      .field("response_param", &self.response_param())
       .field("score", &self.score())
       .field("cursol", &self.cursol())
+      .field("nukidora", &self.nukidora())
+      .field("is_pao", &self.is_pao())
+      .field("pao_player", &self.pao_player())
+      .field("is_rinshan", &self.is_rinshan())
+      .field("is_double_riichi", &self.is_double_riichi())
       .finish()
   }
 }
@@ -1469,10 +1522,20 @@ impl<'a> Player {
     is_tsumo: bool,
     is_riichi: bool,
     is_ippatsu: bool,
+    is_doujun_furiten: bool,
+    is_riichi_furiten: bool,
+    is_responded: bool,
+    response_type: ActionType,
+    response_param: u32,
     score: i32,
     cursol: u32,
+    nukidora: u32,
+    is_pao: bool,
+    pao_player: u32,
+    is_rinshan: bool,
+    is_double_riichi: bool,
   ) -> Self {
-    let mut s = Self([0; 532]);
+    let mut s = Self([0; 592]);
     s.set_name(name);
     s.set_mentsu(mentsu);
     s.set_mentsu_len(mentsu_len);
@@ -1484,8 +1547,18 @@ impl<'a> Player {
     s.set_is_tsumo(is_tsumo);
     s.set_is_riichi(is_riichi);
     s.set_is_ippatsu(is_ippatsu);
+    s.set_is_doujun_furiten(is_doujun_furiten);
+    s.set_is_riichi_furiten(is_riichi_furiten);
+    s.set_is_responded(is_responded);
+    s.set_response_type(response_type);
+    s.set_response_param(response_param);
     s.set_score(score);
     s.set_cursol(cursol);
+    s.set_nukidora(nukidora);
+    s.set_is_pao(is_pao);
+    s.set_pao_player(pao_player);
+    s.set_is_rinshan(is_rinshan);
+    s.set_is_double_riichi(is_double_riichi);
     s
   }
 
@@ -1565,7 +1638,7 @@ impl<'a> Player {
       core::ptr::copy(
         x.as_ptr() as *const u8,
         self.0.as_mut_ptr().add(340),
-        65,
+        78,
       );
     }
   }
@@ -1577,7 +1650,7 @@ impl<'a> Player {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[408..].as_ptr(),
+        self.0[420..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -1593,7 +1666,7 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[408..].as_mut_ptr(),
+        self.0[420..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -1603,7 +1676,7 @@ impl<'a> Player {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 412) }
+    unsafe { flatbuffers::Array::follow(&self.0, 424) }
   }
 
   pub fn set_kawahai(&mut self, x: &[Pai; 20]) {
@@ -1613,8 +1686,8 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(412),
-        100,
+        self.0.as_mut_ptr().add(424),
+        120,
       );
     }
   }
@@ -1626,7 +1699,7 @@ impl<'a> Player {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[512..].as_ptr(),
+        self.0[544..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -1642,7 +1715,7 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[512..].as_mut_ptr(),
+        self.0[544..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -1652,12 +1725,12 @@ impl<'a> Player {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid struct in this slot
-    unsafe { &*(self.0[516..].as_ptr() as *const Pai) }
+    unsafe { &*(self.0[548..].as_ptr() as *const Pai) }
   }
 
   #[allow(clippy::identity_op)]
   pub fn set_tsumohai(&mut self, x: &Pai) {
-    self.0[516..516 + 5].copy_from_slice(&x.0)
+    self.0[548..548 + 6].copy_from_slice(&x.0)
   }
 
   pub fn is_tsumo(&self) -> bool {
@@ -1667,7 +1740,7 @@ impl<'a> Player {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[521..].as_ptr(),
+        self.0[554..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -1683,7 +1756,7 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[521..].as_mut_ptr(),
+        self.0[554..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
@@ -1696,7 +1769,7 @@ impl<'a> Player {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[522..].as_ptr(),
+        self.0[555..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -1712,7 +1785,7 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[522..].as_mut_ptr(),
+        self.0[555..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
@@ -1725,7 +1798,7 @@ impl<'a> Player {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[523..].as_ptr(),
+        self.0[556..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -1741,28 +1814,28 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[523..].as_mut_ptr(),
+        self.0[556..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn score(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+  pub fn is_doujun_furiten(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[524..].as_ptr(),
+        self.0[557..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_score(&mut self, x: i32) {
+  pub fn set_is_doujun_furiten(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1770,28 +1843,28 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[524..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[557..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn cursol(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+  pub fn is_riichi_furiten(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[528..].as_ptr(),
+        self.0[558..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_cursol(&mut self, x: u32) {
+  pub fn set_is_riichi_furiten(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1799,193 +1872,20 @@ impl<'a> Player {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[528..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        self.0[558..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn unpack(&self) -> PlayerT {
-    PlayerT {
-      name: self.name().unpack(),
-      mentsu: { let mentsu = self.mentsu(); flatbuffers::array_init(|i| mentsu.get(i).unpack()) },
-      mentsu_len: self.mentsu_len(),
-      tehai: { let tehai = self.tehai(); flatbuffers::array_init(|i| tehai.get(i).unpack()) },
-      tehai_len: self.tehai_len(),
-      kawahai: { let kawahai = self.kawahai(); flatbuffers::array_init(|i| kawahai.get(i).unpack()) },
-      kawahai_len: self.kawahai_len(),
-      tsumohai: self.tsumohai().unpack(),
-      is_tsumo: self.is_tsumo(),
-      is_riichi: self.is_riichi(),
-      is_ippatsu: self.is_ippatsu(),
-      score: self.score(),
-      cursol: self.cursol(),
-    }
-  }
-}
-
-#[derive(Debug, Clone, PartialEq, Default)]
-pub struct PlayerT {
-  pub name: FixedStringT,
-  pub mentsu: [MentsuT; 4],
-  pub mentsu_len: u32,
-  pub tehai: [PaiT; 13],
-  pub tehai_len: u32,
-  pub kawahai: [PaiT; 20],
-  pub kawahai_len: u32,
-  pub tsumohai: PaiT,
-  pub is_tsumo: bool,
-  pub is_riichi: bool,
-  pub is_ippatsu: bool,
-  pub score: i32,
-  pub cursol: u32,
-}
-impl PlayerT {
-  pub fn pack(&self) -> Player {
-    Player::new(
-      &self.name.pack(),
-      &flatbuffers::array_init(|i| self.mentsu[i].pack()),
-      self.mentsu_len,
-      &flatbuffers::array_init(|i| self.tehai[i].pack()),
-      self.tehai_len,
-      &flatbuffers::array_init(|i| self.kawahai[i].pack()),
-      self.kawahai_len,
-      &self.tsumohai.pack(),
-      self.is_tsumo,
-      self.is_riichi,
-      self.is_ippatsu,
-      self.score,
-      self.cursol,
-    )
-  }
-}
-
-// struct Rule, aligned to 4
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct Rule(pub [u8; 60]);
-impl Default for Rule { 
-  fn default() -> Self { 
-    Self([0; 60])
-  }
-}
-impl core::fmt::Debug for Rule {
-  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("Rule")
-      .field("enable_kuitan", &self.enable_kuitan())
-      .field("enable_kansaki", &self.enable_kansaki())
-      .field("enable_pao", &self.enable_pao())
-      .field("double_ron_type", &self.double_ron_type())
-      .field("initial_score", &self.initial_score())
-      .field("enable_tobi", &self.enable_tobi())
-      .field("enable_wareme", &self.enable_wareme())
-      .field("aka_type", &self.aka_type())
-      .field("shanyu_score", &self.shanyu_score())
-      .field("nannyu_score", &self.nannyu_score())
-      .field("enable_kuinaoshi", &self.enable_kuinaoshi())
-      .field("uradora_type", &self.uradora_type())
-      .field("enable_minus_riichi", &self.enable_minus_riichi())
-      .field("enable_ryanhan_shibari", &self.enable_ryanhan_shibari())
-      .field("furiten_riichi_type", &self.furiten_riichi_type())
-      .field("enable_keiten", &self.enable_keiten())
-      .field("oyanagare_type", &self.oyanagare_type())
-      .field("kan_in_riichi", &self.kan_in_riichi())
-      .field("enable_kiriage", &self.enable_kiriage())
-      .field("enable_agariyame", &self.enable_agariyame())
-      .finish()
-  }
-}
-
-impl flatbuffers::SimpleToVerifyInSlice for Rule {}
-impl<'a> flatbuffers::Follow<'a> for Rule {
-  type Inner = &'a Rule;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a Rule>::follow(buf, loc)
-  }
-}
-impl<'a> flatbuffers::Follow<'a> for &'a Rule {
-  type Inner = &'a Rule;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<Rule>(buf, loc)
-  }
-}
-impl<'b> flatbuffers::Push for Rule {
-    type Output = Rule;
-    #[inline]
-    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const Rule as *const u8, Self::size());
-        dst.copy_from_slice(src);
-    }
-}
-
-impl<'a> flatbuffers::Verifiable for Rule {
-  #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
-  }
-}
-
-impl<'a> Rule {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    enable_kuitan: bool,
-    enable_kansaki: bool,
-    enable_pao: bool,
-    double_ron_type: u32,
-    initial_score: u32,
-    enable_tobi: bool,
-    enable_wareme: bool,
-    aka_type: u32,
-    shanyu_score: i32,
-    nannyu_score: i32,
-    enable_kuinaoshi: bool,
-    uradora_type: i32,
-    enable_minus_riichi: bool,
-    enable_ryanhan_shibari: bool,
-    furiten_riichi_type: u32,
-    enable_keiten: bool,
-    oyanagare_type: u32,
-    kan_in_riichi: u32,
-    enable_kiriage: bool,
-    enable_agariyame: bool,
-  ) -> Self {
-    let mut s = Self([0; 60]);
-    s.set_enable_kuitan(enable_kuitan);
-    s.set_enable_kansaki(enable_kansaki);
-    s.set_enable_pao(enable_pao);
-    s.set_double_ron_type(double_ron_type);
-    s.set_initial_score(initial_score);
-    s.set_enable_tobi(enable_tobi);
-    s.set_enable_wareme(enable_wareme);
-    s.set_aka_type(aka_type);
-    s.set_shanyu_score(shanyu_score);
-    s.set_nannyu_score(nannyu_score);
-    s.set_enable_kuinaoshi(enable_kuinaoshi);
-    s.set_uradora_type(uradora_type);
-    s.set_enable_minus_riichi(enable_minus_riichi);
-    s.set_enable_ryanhan_shibari(enable_ryanhan_shibari);
-    s.set_furiten_riichi_type(furiten_riichi_type);
-    s.set_enable_keiten(enable_keiten);
-    s.set_oyanagare_type(oyanagare_type);
-    s.set_kan_in_riichi(kan_in_riichi);
-    s.set_enable_kiriage(enable_kiriage);
-    s.set_enable_agariyame(enable_agariyame);
-    s
-  }
-
-  pub fn enable_kuitan(&self) -> bool {
+  pub fn is_responded(&self) -> bool {
     let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
+        self.0[559..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -1993,7 +1893,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_enable_kuitan(&mut self, x: bool) {
+  pub fn set_is_responded(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2001,28 +1901,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
+        self.0[559..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_kansaki(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn response_type(&self) -> ActionType {
+    let mut mem = core::mem::MaybeUninit::<<ActionType as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[1..].as_ptr(),
+        self.0[560..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<ActionType as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_kansaki(&mut self, x: bool) {
+  pub fn set_response_type(&mut self, x: ActionType) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2030,28 +1930,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[1..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[560..].as_mut_ptr(),
+        core::mem::size_of::<<ActionType as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_pao(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn response_param(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2..].as_ptr(),
+        self.0[564..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_pao(&mut self, x: bool) {
+  pub fn set_response_param(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2059,28 +1959,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[564..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn double_ron_type(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+  pub fn score(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[4..].as_ptr(),
+        self.0[568..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_double_ron_type(&mut self, x: u32) {
+  pub fn set_score(&mut self, x: i32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2088,20 +1988,20 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[4..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        self.0[568..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn initial_score(&self) -> u32 {
+  pub fn cursol(&self) -> u32 {
     let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[8..].as_ptr(),
+        self.0[572..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2109,7 +2009,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_initial_score(&mut self, x: u32) {
+  pub fn set_cursol(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2117,28 +2017,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[8..].as_mut_ptr(),
+        self.0[572..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_tobi(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn nukidora(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[12..].as_ptr(),
+        self.0[576..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_tobi(&mut self, x: bool) {
+  pub fn set_nukidora(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2146,20 +2046,20 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[12..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[576..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_wareme(&self) -> bool {
+  pub fn is_pao(&self) -> bool {
     let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[13..].as_ptr(),
+        self.0[580..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -2167,7 +2067,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_enable_wareme(&mut self, x: bool) {
+  pub fn set_is_pao(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2175,20 +2075,20 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[13..].as_mut_ptr(),
+        self.0[580..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn aka_type(&self) -> u32 {
+  pub fn pao_player(&self) -> u32 {
     let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[16..].as_ptr(),
+        self.0[584..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2196,7 +2096,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_aka_type(&mut self, x: u32) {
+  pub fn set_pao_player(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2204,28 +2104,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[16..].as_mut_ptr(),
+        self.0[584..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn shanyu_score(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+  pub fn is_rinshan(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[20..].as_ptr(),
+        self.0[588..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_shanyu_score(&mut self, x: i32) {
+  pub fn set_is_rinshan(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2233,28 +2133,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[20..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[588..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn nannyu_score(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+  pub fn is_double_riichi(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[24..].as_ptr(),
+        self.0[589..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_nannyu_score(&mut self, x: i32) {
+  pub fn set_is_double_riichi(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2262,28 +2162,218 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[24..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[589..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_kuinaoshi(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn unpack(&self) -> PlayerT {
+    PlayerT {
+      name: self.name().unpack(),
+      mentsu: { let mentsu = self.mentsu(); flatbuffers::array_init(|i| mentsu.get(i).unpack()) },
+      mentsu_len: self.mentsu_len(),
+      tehai: { let tehai = self.tehai(); flatbuffers::array_init(|i| tehai.get(i).unpack()) },
+      tehai_len: self.tehai_len(),
+      kawahai: { let kawahai = self.kawahai(); flatbuffers::array_init(|i| kawahai.get(i).unpack()) },
+      kawahai_len: self.kawahai_len(),
+      tsumohai: self.tsumohai().unpack(),
+      is_tsumo: self.is_tsumo(),
+      is_riichi: self.is_riichi(),
+      is_ippatsu: self.is_ippatsu(),
+      is_doujun_furiten: self.is_doujun_furiten(),
+      is_riichi_furiten: self.is_riichi_furiten(),
+      is_responded: self.is_responded(),
+      response_type: self.response_type(),
+      response_param: self.response_param(),
+      score: self.score(),
+      cursol: self.cursol(),
+      nukidora: self.nukidora(),
+      is_pao: self.is_pao(),
+      pao_player: self.pao_player(),
+      is_rinshan: self.is_rinshan(),
+      is_double_riichi: self.is_double_riichi(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerT {
+  pub name: FixedStringT,
+  pub mentsu: [MentsuT; 4],
+  pub mentsu_len: u32,
+  pub tehai: [PaiT; 13],
+  pub tehai_len: u32,
+  pub kawahai: [PaiT; 20],
+  pub kawahai_len: u32,
+  pub tsumohai: PaiT,
+  pub is_tsumo: bool,
+  pub is_riichi: bool,
+  pub is_ippatsu: bool,
+  pub is_doujun_furiten: bool,
+  pub is_riichi_furiten: bool,
+  pub is_responded: bool,
+  pub response_type: ActionType,
+  pub response_param: u32,
+  pub score: i32,
+  pub cursol: u32,
+  pub nukidora: u32,
+  pub is_pao: bool,
+  pub pao_player: u32,
+  pub is_rinshan: bool,
+  pub is_double_riichi: bool,
+}
+impl PlayerT {
+  pub fn pack(&self) -> Player {
+    Player::new(
+      &self.name.pack(),
+      &flatbuffers::array_init(|i| self.mentsu[i].pack()),
+      self.mentsu_len,
+      &flatbuffers::array_init(|i| self.tehai[i].pack()),
+      self.tehai_len,
+      &flatbuffers::array_init(|i| self.kawahai[i].pack()),
+      self.kawahai_len,
+      &self.tsumohai.pack(),
+      self.is_tsumo,
+      self.is_riichi,
+      self.is_ippatsu,
+      self.is_doujun_furiten,
+      self.is_riichi_furiten,
+      self.is_responded,
+      self.response_type,
+      self.response_param,
+      self.score,
+      self.cursol,
+      self.nukidora,
+      self.is_pao,
+      self.pao_player,
+      self.is_rinshan,
+      self.is_double_riichi,
+    )
+  }
+}
+
+// struct PublicPlayer, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PublicPlayer(pub [u8; 224]);
+impl Default for PublicPlayer { 
+  fn default() -> Self { 
+    Self([0; 224])
+  }
+}
+impl core::fmt::Debug for PublicPlayer {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("PublicPlayer")
+      .field("mentsu", &self.mentsu())
+      .field("mentsu_len", &self.mentsu_len())
+      .field("tehai_len", &self.tehai_len())
+      .field("kawahai", &self.kawahai())
+      .field("kawahai_len", &self.kawahai_len())
+      .field("is_tsumo", &self.is_tsumo())
+      .field("is_riichi", &self.is_riichi())
+      .field("score", &self.score())
+      .field("nukidora", &self.nukidora())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PublicPlayer {}
+impl<'a> flatbuffers::Follow<'a> for PublicPlayer {
+  type Inner = &'a PublicPlayer;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a PublicPlayer>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a PublicPlayer {
+  type Inner = &'a PublicPlayer;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<PublicPlayer>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for PublicPlayer {
+    type Output = PublicPlayer;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const PublicPlayer as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for PublicPlayer {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> PublicPlayer {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    mentsu: &[Mentsu; 4],
+    mentsu_len: u32,
+    tehai_len: u32,
+    kawahai: &[Pai; 20],
+    kawahai_len: u32,
+    is_tsumo: bool,
+    is_riichi: bool,
+    score: i32,
+    nukidora: u32,
+  ) -> Self {
+    let mut s = Self([0; 224]);
+    s.set_mentsu(mentsu);
+    s.set_mentsu_len(mentsu_len);
+    s.set_tehai_len(tehai_len);
+    s.set_kawahai(kawahai);
+    s.set_kawahai_len(kawahai_len);
+    s.set_is_tsumo(is_tsumo);
+    s.set_is_riichi(is_riichi);
+    s.set_score(score);
+    s.set_nukidora(nukidora);
+    s
+  }
+
+  pub fn mentsu(&'a self) -> flatbuffers::Array<'a, Mentsu, 4> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 0) }
+  }
+
+  pub fn set_mentsu(&mut self, x: &[Mentsu; 4]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(0),
+        80,
+      );
+    }
+  }
+
+  pub fn mentsu_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[28..].as_ptr(),
+        self.0[80..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_kuinaoshi(&mut self, x: bool) {
+  pub fn set_mentsu_len(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2291,28 +2381,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[28..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[80..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn uradora_type(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+  pub fn tehai_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[32..].as_ptr(),
+        self.0[84..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_uradora_type(&mut self, x: i32) {
+  pub fn set_tehai_len(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2320,28 +2410,48 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[32..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[84..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_minus_riichi(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn kawahai(&'a self) -> flatbuffers::Array<'a, Pai, 20> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 88) }
+  }
+
+  pub fn set_kawahai(&mut self, x: &[Pai; 20]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(88),
+        120,
+      );
+    }
+  }
+
+  pub fn kawahai_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[36..].as_ptr(),
+        self.0[208..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_minus_riichi(&mut self, x: bool) {
+  pub fn set_kawahai_len(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2349,20 +2459,20 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[36..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[208..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_ryanhan_shibari(&self) -> bool {
+  pub fn is_tsumo(&self) -> bool {
     let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[37..].as_ptr(),
+        self.0[212..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -2370,7 +2480,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_enable_ryanhan_shibari(&mut self, x: bool) {
+  pub fn set_is_tsumo(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2378,28 +2488,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[37..].as_mut_ptr(),
+        self.0[212..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn furiten_riichi_type(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+  pub fn is_riichi(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[40..].as_ptr(),
+        self.0[213..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_furiten_riichi_type(&mut self, x: u32) {
+  pub fn set_is_riichi(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2407,28 +2517,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[40..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        self.0[213..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_keiten(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn score(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[44..].as_ptr(),
+        self.0[216..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_keiten(&mut self, x: bool) {
+  pub fn set_score(&mut self, x: i32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2436,20 +2546,20 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[44..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[216..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn oyanagare_type(&self) -> u32 {
+  pub fn nukidora(&self) -> u32 {
     let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[48..].as_ptr(),
+        self.0[220..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2457,7 +2567,7 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_oyanagare_type(&mut self, x: u32) {
+  pub fn set_nukidora(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2465,20 +2575,1441 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[48..].as_mut_ptr(),
+        self.0[220..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn kan_in_riichi(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+  pub fn unpack(&self) -> PublicPlayerT {
+    PublicPlayerT {
+      mentsu: { let mentsu = self.mentsu(); flatbuffers::array_init(|i| mentsu.get(i).unpack()) },
+      mentsu_len: self.mentsu_len(),
+      tehai_len: self.tehai_len(),
+      kawahai: { let kawahai = self.kawahai(); flatbuffers::array_init(|i| kawahai.get(i).unpack()) },
+      kawahai_len: self.kawahai_len(),
+      is_tsumo: self.is_tsumo(),
+      is_riichi: self.is_riichi(),
+      score: self.score(),
+      nukidora: self.nukidora(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PublicPlayerT {
+  pub mentsu: [MentsuT; 4],
+  pub mentsu_len: u32,
+  pub tehai_len: u32,
+  pub kawahai: [PaiT; 20],
+  pub kawahai_len: u32,
+  pub is_tsumo: bool,
+  pub is_riichi: bool,
+  pub score: i32,
+  pub nukidora: u32,
+}
+impl PublicPlayerT {
+  pub fn pack(&self) -> PublicPlayer {
+    PublicPlayer::new(
+      &flatbuffers::array_init(|i| self.mentsu[i].pack()),
+      self.mentsu_len,
+      self.tehai_len,
+      &flatbuffers::array_init(|i| self.kawahai[i].pack()),
+      self.kawahai_len,
+      self.is_tsumo,
+      self.is_riichi,
+      self.score,
+      self.nukidora,
+    )
+  }
+}
+
+// struct PlayerView, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PlayerView(pub [u8; 1124]);
+impl Default for PlayerView { 
+  fn default() -> Self { 
+    Self([0; 1124])
+  }
+}
+impl core::fmt::Debug for PlayerView {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("PlayerView")
+      .field("player_index", &self.player_index())
+      .field("tehai", &self.tehai())
+      .field("tehai_len", &self.tehai_len())
+      .field("tsumohai", &self.tsumohai())
+      .field("is_tsumo", &self.is_tsumo())
+      .field("is_furiten", &self.is_furiten())
+      .field("players", &self.players())
+      .field("player_len", &self.player_len())
+      .field("bakaze", &self.bakaze())
+      .field("oya", &self.oya())
+      .field("tsumobou", &self.tsumobou())
+      .field("riichibou", &self.riichibou())
+      .field("teban", &self.teban())
+      .field("remain", &self.remain())
+      .field("dora", &self.dora())
+      .field("dora_len", &self.dora_len())
+      .field("is_kyoku_end", &self.is_kyoku_end())
+      .field("wall_hash", &self.wall_hash())
+      .field("server_seed_hash", &self.server_seed_hash())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PlayerView {}
+impl<'a> flatbuffers::Follow<'a> for PlayerView {
+  type Inner = &'a PlayerView;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a PlayerView>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a PlayerView {
+  type Inner = &'a PlayerView;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<PlayerView>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for PlayerView {
+    type Output = PlayerView;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const PlayerView as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for PlayerView {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> PlayerView {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    player_index: u32,
+    tehai: &[Pai; 13],
+    tehai_len: u32,
+    tsumohai: &Pai,
+    is_tsumo: bool,
+    is_furiten: bool,
+    players: &[PublicPlayer; 4],
+    player_len: u32,
+    bakaze: u32,
+    oya: u32,
+    tsumobou: u32,
+    riichibou: u32,
+    teban: u32,
+    remain: u32,
+    dora: &[Pai; 5],
+    dora_len: u32,
+    is_kyoku_end: bool,
+    wall_hash: &[u8; 32],
+    server_seed_hash: &[u8; 32],
+  ) -> Self {
+    let mut s = Self([0; 1124]);
+    s.set_player_index(player_index);
+    s.set_tehai(tehai);
+    s.set_tehai_len(tehai_len);
+    s.set_tsumohai(tsumohai);
+    s.set_is_tsumo(is_tsumo);
+    s.set_is_furiten(is_furiten);
+    s.set_players(players);
+    s.set_player_len(player_len);
+    s.set_bakaze(bakaze);
+    s.set_oya(oya);
+    s.set_tsumobou(tsumobou);
+    s.set_riichibou(riichibou);
+    s.set_teban(teban);
+    s.set_remain(remain);
+    s.set_dora(dora);
+    s.set_dora_len(dora_len);
+    s.set_is_kyoku_end(is_kyoku_end);
+    s.set_wall_hash(wall_hash);
+    s.set_server_seed_hash(server_seed_hash);
+    s
+  }
+
+  pub fn player_index(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_player_index(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn tehai(&'a self) -> flatbuffers::Array<'a, Pai, 13> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 4) }
+  }
+
+  pub fn set_tehai(&mut self, x: &[Pai; 13]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(4),
+        78,
+      );
+    }
+  }
+
+  pub fn tehai_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[84..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_tehai_len(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[84..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn tsumohai(&self) -> &Pai {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[88..].as_ptr() as *const Pai) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_tsumohai(&mut self, x: &Pai) {
+    self.0[88..88 + 6].copy_from_slice(&x.0)
+  }
+
+  pub fn is_tsumo(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[94..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_tsumo(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[94..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_furiten(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[95..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_furiten(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[95..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn players(&'a self) -> flatbuffers::Array<'a, PublicPlayer, 4> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 96) }
+  }
+
+  pub fn set_players(&mut self, x: &[PublicPlayer; 4]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(96),
+        896,
+      );
+    }
+  }
+
+  pub fn player_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[992..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_player_len(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[992..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn bakaze(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[996..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_bakaze(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[996..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn oya(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1000..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_oya(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1000..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn tsumobou(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1004..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_tsumobou(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1004..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn riichibou(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1008..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_riichibou(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1008..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn teban(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1012..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_teban(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1012..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn remain(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1016..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_remain(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1016..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn dora(&'a self) -> flatbuffers::Array<'a, Pai, 5> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 1020) }
+  }
+
+  pub fn set_dora(&mut self, x: &[Pai; 5]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(1020),
+        30,
+      );
+    }
+  }
+
+  pub fn dora_len(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1052..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_dora_len(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1052..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_kyoku_end(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1056..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_kyoku_end(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1056..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn wall_hash(&'a self) -> flatbuffers::Array<'a, u8, 32> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 1057) }
+  }
+
+  pub fn set_wall_hash(&mut self, items: &[u8; 32]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 1057, items) };
+  }
+
+  pub fn server_seed_hash(&'a self) -> flatbuffers::Array<'a, u8, 32> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 1089) }
+  }
+
+  pub fn set_server_seed_hash(&mut self, items: &[u8; 32]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 1089, items) };
+  }
+
+  pub fn unpack(&self) -> PlayerViewT {
+    PlayerViewT {
+      player_index: self.player_index(),
+      tehai: { let tehai = self.tehai(); flatbuffers::array_init(|i| tehai.get(i).unpack()) },
+      tehai_len: self.tehai_len(),
+      tsumohai: self.tsumohai().unpack(),
+      is_tsumo: self.is_tsumo(),
+      is_furiten: self.is_furiten(),
+      players: { let players = self.players(); flatbuffers::array_init(|i| players.get(i).unpack()) },
+      player_len: self.player_len(),
+      bakaze: self.bakaze(),
+      oya: self.oya(),
+      tsumobou: self.tsumobou(),
+      riichibou: self.riichibou(),
+      teban: self.teban(),
+      remain: self.remain(),
+      dora: { let dora = self.dora(); flatbuffers::array_init(|i| dora.get(i).unpack()) },
+      dora_len: self.dora_len(),
+      is_kyoku_end: self.is_kyoku_end(),
+      wall_hash: self.wall_hash().into(),
+      server_seed_hash: self.server_seed_hash().into(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerViewT {
+  pub player_index: u32,
+  pub tehai: [PaiT; 13],
+  pub tehai_len: u32,
+  pub tsumohai: PaiT,
+  pub is_tsumo: bool,
+  pub is_furiten: bool,
+  pub players: [PublicPlayerT; 4],
+  pub player_len: u32,
+  pub bakaze: u32,
+  pub oya: u32,
+  pub tsumobou: u32,
+  pub riichibou: u32,
+  pub teban: u32,
+  pub remain: u32,
+  pub dora: [PaiT; 5],
+  pub dora_len: u32,
+  pub is_kyoku_end: bool,
+  pub wall_hash: [u8; 32],
+  pub server_seed_hash: [u8; 32],
+}
+impl PlayerViewT {
+  pub fn pack(&self) -> PlayerView {
+    PlayerView::new(
+      self.player_index,
+      &flatbuffers::array_init(|i| self.tehai[i].pack()),
+      self.tehai_len,
+      &self.tsumohai.pack(),
+      self.is_tsumo,
+      self.is_furiten,
+      &flatbuffers::array_init(|i| self.players[i].pack()),
+      self.player_len,
+      self.bakaze,
+      self.oya,
+      self.tsumobou,
+      self.riichibou,
+      self.teban,
+      self.remain,
+      &flatbuffers::array_init(|i| self.dora[i].pack()),
+      self.dora_len,
+      self.is_kyoku_end,
+      &self.wall_hash,
+      &self.server_seed_hash,
+    )
+  }
+}
+
+// struct Rule, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rule(pub [u8; 60]);
+impl Default for Rule { 
+  fn default() -> Self { 
+    Self([0; 60])
+  }
+}
+impl core::fmt::Debug for Rule {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Rule")
+      .field("enable_kuitan", &self.enable_kuitan())
+      .field("enable_atozuke", &self.enable_atozuke())
+      .field("enable_pao", &self.enable_pao())
+      .field("enable_tobi", &self.enable_tobi())
+      .field("enable_wareme", &self.enable_wareme())
+      .field("enable_kuinaoshi", &self.enable_kuinaoshi())
+      .field("enable_kiriage", &self.enable_kiriage())
+      .field("enable_agariyame", &self.enable_agariyame())
+      .field("enable_minus_riichi", &self.enable_minus_riichi())
+      .field("enable_ryanhan_shibari", &self.enable_ryanhan_shibari())
+      .field("enable_keiten", &self.enable_keiten())
+      .field("enable_glass_pai", &self.enable_glass_pai())
+      .field("aka_type", &self.aka_type())
+      .field("shanyu_score", &self.shanyu_score())
+      .field("nannyu_score", &self.nannyu_score())
+      .field("uradora_type", &self.uradora_type())
+      .field("furiten_riichi_type", &self.furiten_riichi_type())
+      .field("oyanagare_type", &self.oyanagare_type())
+      .field("double_ron_type", &self.double_ron_type())
+      .field("initial_score", &self.initial_score())
+      .field("kan_in_riichi_type", &self.kan_in_riichi_type())
+      .field("enable_kansaki", &self.enable_kansaki())
+      .field("enable_tonpu", &self.enable_tonpu())
+      .field("sanma_tsumo_type", &self.sanma_tsumo_type())
+      .field("renhou_type", &self.renhou_type())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Rule {}
+impl<'a> flatbuffers::Follow<'a> for Rule {
+  type Inner = &'a Rule;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Rule>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Rule {
+  type Inner = &'a Rule;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Rule>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Rule {
+    type Output = Rule;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const Rule as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Rule {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Rule {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    enable_kuitan: bool,
+    enable_atozuke: bool,
+    enable_pao: bool,
+    enable_tobi: bool,
+    enable_wareme: bool,
+    enable_kuinaoshi: bool,
+    enable_kiriage: bool,
+    enable_agariyame: bool,
+    enable_minus_riichi: bool,
+    enable_ryanhan_shibari: bool,
+    enable_keiten: bool,
+    enable_glass_pai: bool,
+    aka_type: u32,
+    shanyu_score: i32,
+    nannyu_score: i32,
+    uradora_type: i32,
+    furiten_riichi_type: u32,
+    oyanagare_type: u32,
+    double_ron_type: u32,
+    initial_score: u32,
+    kan_in_riichi_type: u32,
+    enable_kansaki: bool,
+    enable_tonpu: bool,
+    sanma_tsumo_type: u32,
+    renhou_type: u32,
+  ) -> Self {
+    let mut s = Self([0; 60]);
+    s.set_enable_kuitan(enable_kuitan);
+    s.set_enable_atozuke(enable_atozuke);
+    s.set_enable_pao(enable_pao);
+    s.set_enable_tobi(enable_tobi);
+    s.set_enable_wareme(enable_wareme);
+    s.set_enable_kuinaoshi(enable_kuinaoshi);
+    s.set_enable_kiriage(enable_kiriage);
+    s.set_enable_agariyame(enable_agariyame);
+    s.set_enable_minus_riichi(enable_minus_riichi);
+    s.set_enable_ryanhan_shibari(enable_ryanhan_shibari);
+    s.set_enable_keiten(enable_keiten);
+    s.set_enable_glass_pai(enable_glass_pai);
+    s.set_aka_type(aka_type);
+    s.set_shanyu_score(shanyu_score);
+    s.set_nannyu_score(nannyu_score);
+    s.set_uradora_type(uradora_type);
+    s.set_furiten_riichi_type(furiten_riichi_type);
+    s.set_oyanagare_type(oyanagare_type);
+    s.set_double_ron_type(double_ron_type);
+    s.set_initial_score(initial_score);
+    s.set_kan_in_riichi_type(kan_in_riichi_type);
+    s.set_enable_kansaki(enable_kansaki);
+    s.set_enable_tonpu(enable_tonpu);
+    s.set_sanma_tsumo_type(sanma_tsumo_type);
+    s.set_renhou_type(renhou_type);
+    s
+  }
+
+  pub fn enable_kuitan(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_kuitan(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_atozuke(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_atozuke(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_pao(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[2..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_pao(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[2..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_tobi(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_tobi(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_wareme(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_wareme(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_kuinaoshi(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[5..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_kuinaoshi(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[5..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_kiriage(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[6..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_kiriage(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[6..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_agariyame(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[7..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_agariyame(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[7..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_minus_riichi(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_minus_riichi(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_ryanhan_shibari(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[9..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_ryanhan_shibari(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[9..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_keiten(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[10..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_keiten(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[10..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_glass_pai(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[11..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_glass_pai(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[11..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn aka_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_aka_type(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn shanyu_score(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_shanyu_score(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn nannyu_score(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[52..].as_ptr(),
+        self.0[20..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_nannyu_score(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[20..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn uradora_type(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_uradora_type(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn furiten_riichi_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[28..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_furiten_riichi_type(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[28..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn oyanagare_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[32..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_oyanagare_type(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[32..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn double_ron_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[36..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_double_ron_type(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[36..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn initial_score(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[40..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_initial_score(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[40..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn kan_in_riichi_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[44..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2486,7 +4017,65 @@ impl<'a> Rule {
     })
   }
 
-  pub fn set_kan_in_riichi(&mut self, x: u32) {
+  pub fn set_kan_in_riichi_type(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[44..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_kansaki(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[48..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_kansaki(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[48..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn enable_tonpu(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[49..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_enable_tonpu(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2494,28 +4083,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[52..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+        self.0[49..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_kiriage(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn sanma_tsumo_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[56..].as_ptr(),
+        self.0[52..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_kiriage(&mut self, x: bool) {
+  pub fn set_sanma_tsumo_type(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2523,28 +4112,28 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[56..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[52..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn enable_agariyame(&self) -> bool {
-    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+  pub fn renhou_type(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[57..].as_ptr(),
+        self.0[56..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_enable_agariyame(&mut self, x: bool) {
+  pub fn set_renhou_type(&mut self, x: u32) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2552,8 +4141,8 @@ impl<'a> Rule {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[57..].as_mut_ptr(),
-        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+        self.0[56..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
@@ -2561,25 +4150,30 @@ impl<'a> Rule {
   pub fn unpack(&self) -> RuleT {
     RuleT {
       enable_kuitan: self.enable_kuitan(),
-      enable_kansaki: self.enable_kansaki(),
+      enable_atozuke: self.enable_atozuke(),
       enable_pao: self.enable_pao(),
-      double_ron_type: self.double_ron_type(),
-      initial_score: self.initial_score(),
       enable_tobi: self.enable_tobi(),
       enable_wareme: self.enable_wareme(),
+      enable_kuinaoshi: self.enable_kuinaoshi(),
+      enable_kiriage: self.enable_kiriage(),
+      enable_agariyame: self.enable_agariyame(),
+      enable_minus_riichi: self.enable_minus_riichi(),
+      enable_ryanhan_shibari: self.enable_ryanhan_shibari(),
+      enable_keiten: self.enable_keiten(),
+      enable_glass_pai: self.enable_glass_pai(),
       aka_type: self.aka_type(),
       shanyu_score: self.shanyu_score(),
       nannyu_score: self.nannyu_score(),
-      enable_kuinaoshi: self.enable_kuinaoshi(),
       uradora_type: self.uradora_type(),
-      enable_minus_riichi: self.enable_minus_riichi(),
-      enable_ryanhan_shibari: self.enable_ryanhan_shibari(),
       furiten_riichi_type: self.furiten_riichi_type(),
-      enable_keiten: self.enable_keiten(),
       oyanagare_type: self.oyanagare_type(),
-      kan_in_riichi: self.kan_in_riichi(),
-      enable_kiriage: self.enable_kiriage(),
-      enable_agariyame: self.enable_agariyame(),
+      double_ron_type: self.double_ron_type(),
+      initial_score: self.initial_score(),
+      kan_in_riichi_type: self.kan_in_riichi_type(),
+      enable_kansaki: self.enable_kansaki(),
+      enable_tonpu: self.enable_tonpu(),
+      sanma_tsumo_type: self.sanma_tsumo_type(),
+      renhou_type: self.renhou_type(),
     }
   }
 }
@@ -2587,49 +4181,59 @@ impl<'a> Rule {
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct RuleT {
   pub enable_kuitan: bool,
-  pub enable_kansaki: bool,
+  pub enable_atozuke: bool,
   pub enable_pao: bool,
-  pub double_ron_type: u32,
-  pub initial_score: u32,
   pub enable_tobi: bool,
   pub enable_wareme: bool,
+  pub enable_kuinaoshi: bool,
+  pub enable_kiriage: bool,
+  pub enable_agariyame: bool,
+  pub enable_minus_riichi: bool,
+  pub enable_ryanhan_shibari: bool,
+  pub enable_keiten: bool,
+  pub enable_glass_pai: bool,
   pub aka_type: u32,
   pub shanyu_score: i32,
   pub nannyu_score: i32,
-  pub enable_kuinaoshi: bool,
   pub uradora_type: i32,
-  pub enable_minus_riichi: bool,
-  pub enable_ryanhan_shibari: bool,
   pub furiten_riichi_type: u32,
-  pub enable_keiten: bool,
   pub oyanagare_type: u32,
-  pub kan_in_riichi: u32,
-  pub enable_kiriage: bool,
-  pub enable_agariyame: bool,
+  pub double_ron_type: u32,
+  pub initial_score: u32,
+  pub kan_in_riichi_type: u32,
+  pub enable_kansaki: bool,
+  pub enable_tonpu: bool,
+  pub sanma_tsumo_type: u32,
+  pub renhou_type: u32,
 }
 impl RuleT {
   pub fn pack(&self) -> Rule {
     Rule::new(
       self.enable_kuitan,
-      self.enable_kansaki,
+      self.enable_atozuke,
       self.enable_pao,
-      self.double_ron_type,
-      self.initial_score,
       self.enable_tobi,
       self.enable_wareme,
+      self.enable_kuinaoshi,
+      self.enable_kiriage,
+      self.enable_agariyame,
+      self.enable_minus_riichi,
+      self.enable_ryanhan_shibari,
+      self.enable_keiten,
+      self.enable_glass_pai,
       self.aka_type,
       self.shanyu_score,
       self.nannyu_score,
-      self.enable_kuinaoshi,
       self.uradora_type,
-      self.enable_minus_riichi,
-      self.enable_ryanhan_shibari,
       self.furiten_riichi_type,
-      self.enable_keiten,
       self.oyanagare_type,
-      self.kan_in_riichi,
-      self.enable_kiriage,
-      self.enable_agariyame,
+      self.double_ron_type,
+      self.initial_score,
+      self.kan_in_riichi_type,
+      self.enable_kansaki,
+      self.enable_tonpu,
+      self.sanma_tsumo_type,
+      self.renhou_type,
     )
   }
 }
@@ -2637,10 +4241,10 @@ impl RuleT {
 // struct GameState, aligned to 8
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct GameState(pub [u8; 3200]);
+pub struct GameState(pub [u8; 3800]);
 impl Default for GameState { 
   fn default() -> Self { 
-    Self([0; 3200])
+    Self([0; 3800])
   }
 }
 impl core::fmt::Debug for GameState {
@@ -2649,6 +4253,16 @@ impl core::fmt::Debug for GameState {
       .field("title", &self.title())
       .field("game_id", &self.game_id())
       .field("kyoku_id", &self.kyoku_id())
+      .field("seed", &self.seed())
+      .field("shuffle_count", &self.shuffle_count())
+      .field("is_fair_shuffle", &self.is_fair_shuffle())
+      .field("server_seed", &self.server_seed())
+      .field("committed_server_seed", &self.committed_server_seed())
+      .field("server_seed_hash", &self.server_seed_hash())
+      .field("is_seed_committed", &self.is_seed_committed())
+      .field("client_seeds", &self.client_seeds())
+      .field("shuffle_client_seeds", &self.shuffle_client_seeds())
+      .field("wall_hash", &self.wall_hash())
       .field("players", &self.players())
       .field("player_len", &self.player_len())
       .field("bakaze", &self.bakaze())
@@ -2658,11 +4272,18 @@ impl core::fmt::Debug for GameState {
       .field("teban", &self.teban())
       .field("taku", &self.taku())
       .field("taku_cursol", &self.taku_cursol())
+      .field("dice", &self.dice())
       .field("dora_len", &self.dora_len())
       .field("uradora_len", &self.uradora_len())
       .field("is_non_duplicate", &self.is_non_duplicate())
       .field("seq", &self.seq())
+      .field("is_kyoku_end", &self.is_kyoku_end())
+      .field("is_renchan", &self.is_renchan())
+      .field("is_ryuukyoku", &self.is_ryuukyoku())
+      .field("is_game_end", &self.is_game_end())
       .field("rule", &self.rule())
+      .field("is_chankan", &self.is_chankan())
+      .field("chankan_pai", &self.chankan_pai())
       .finish()
   }
 }
@@ -2689,102 +4310,294 @@ impl<'b> flatbuffers::Push for GameState {
         let src = ::core::slice::from_raw_parts(self as *const GameState as *const u8, Self::size());
         dst.copy_from_slice(src);
     }
-}
+}
+
+impl<'a> flatbuffers::Verifiable for GameState {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> GameState {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    title: &FixedString,
+    game_id: &[u8; 16],
+    kyoku_id: u64,
+    seed: u64,
+    shuffle_count: u32,
+    is_fair_shuffle: bool,
+    server_seed: &[u8; 32],
+    committed_server_seed: &[u8; 32],
+    server_seed_hash: &[u8; 32],
+    is_seed_committed: bool,
+    client_seeds: &[u64; 4],
+    shuffle_client_seeds: &[u64; 4],
+    wall_hash: &[u8; 32],
+    players: &[Player; 4],
+    player_len: u32,
+    bakaze: u32,
+    oya: u32,
+    tsumobou: u32,
+    riichibou: u32,
+    teban: u32,
+    taku: &Taku,
+    taku_cursol: u32,
+    dice: &[u8; 2],
+    dora_len: u32,
+    uradora_len: u32,
+    is_non_duplicate: bool,
+    seq: u32,
+    is_kyoku_end: bool,
+    is_renchan: bool,
+    is_ryuukyoku: bool,
+    is_game_end: bool,
+    rule: &Rule,
+    is_chankan: bool,
+    chankan_pai: &Pai,
+  ) -> Self {
+    let mut s = Self([0; 3800]);
+    s.set_title(title);
+    s.set_game_id(game_id);
+    s.set_kyoku_id(kyoku_id);
+    s.set_seed(seed);
+    s.set_shuffle_count(shuffle_count);
+    s.set_is_fair_shuffle(is_fair_shuffle);
+    s.set_server_seed(server_seed);
+    s.set_committed_server_seed(committed_server_seed);
+    s.set_server_seed_hash(server_seed_hash);
+    s.set_is_seed_committed(is_seed_committed);
+    s.set_client_seeds(client_seeds);
+    s.set_shuffle_client_seeds(shuffle_client_seeds);
+    s.set_wall_hash(wall_hash);
+    s.set_players(players);
+    s.set_player_len(player_len);
+    s.set_bakaze(bakaze);
+    s.set_oya(oya);
+    s.set_tsumobou(tsumobou);
+    s.set_riichibou(riichibou);
+    s.set_teban(teban);
+    s.set_taku(taku);
+    s.set_taku_cursol(taku_cursol);
+    s.set_dice(dice);
+    s.set_dora_len(dora_len);
+    s.set_uradora_len(uradora_len);
+    s.set_is_non_duplicate(is_non_duplicate);
+    s.set_seq(seq);
+    s.set_is_kyoku_end(is_kyoku_end);
+    s.set_is_renchan(is_renchan);
+    s.set_is_ryuukyoku(is_ryuukyoku);
+    s.set_is_game_end(is_game_end);
+    s.set_rule(rule);
+    s.set_is_chankan(is_chankan);
+    s.set_chankan_pai(chankan_pai);
+    s
+  }
+
+  pub fn title(&self) -> &FixedString {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[0..].as_ptr() as *const FixedString) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_title(&mut self, x: &FixedString) {
+    self.0[0..0 + 256].copy_from_slice(&x.0)
+  }
+
+  pub fn game_id(&'a self) -> flatbuffers::Array<'a, u8, 16> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 256) }
+  }
+
+  pub fn set_game_id(&mut self, items: &[u8; 16]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 256, items) };
+  }
+
+  pub fn kyoku_id(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[272..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_kyoku_id(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[272..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn seed(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[280..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_seed(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[280..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn shuffle_count(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[288..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_shuffle_count(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[288..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_fair_shuffle(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[292..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_fair_shuffle(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[292..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
 
-impl<'a> flatbuffers::Verifiable for GameState {
-  #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
+  pub fn server_seed(&'a self) -> flatbuffers::Array<'a, u8, 32> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 293) }
   }
-}
 
-impl<'a> GameState {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    title: &FixedString,
-    game_id: &[u8; 16],
-    kyoku_id: u64,
-    players: &[Player; 4],
-    player_len: u32,
-    bakaze: u32,
-    oya: u32,
-    tsumobou: u32,
-    riichibou: u32,
-    teban: u32,
-    taku: &Taku,
-    taku_cursol: u32,
-    dora_len: u32,
-    uradora_len: u32,
-    is_non_duplicate: bool,
-    seq: u32,
-    rule: &Rule,
-  ) -> Self {
-    let mut s = Self([0; 3200]);
-    s.set_title(title);
-    s.set_game_id(game_id);
-    s.set_kyoku_id(kyoku_id);
-    s.set_players(players);
-    s.set_player_len(player_len);
-    s.set_bakaze(bakaze);
-    s.set_oya(oya);
-    s.set_tsumobou(tsumobou);
-    s.set_riichibou(riichibou);
-    s.set_teban(teban);
-    s.set_taku(taku);
-    s.set_taku_cursol(taku_cursol);
-    s.set_dora_len(dora_len);
-    s.set_uradora_len(uradora_len);
-    s.set_is_non_duplicate(is_non_duplicate);
-    s.set_seq(seq);
-    s.set_rule(rule);
-    s
+  pub fn set_server_seed(&mut self, items: &[u8; 32]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 293, items) };
   }
 
-  pub fn title(&self) -> &FixedString {
+  pub fn committed_server_seed(&'a self) -> flatbuffers::Array<'a, u8, 32> {
     // Safety:
     // Created from a valid Table for this object
-    // Which contains a valid struct in this slot
-    unsafe { &*(self.0[0..].as_ptr() as *const FixedString) }
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 325) }
   }
 
-  #[allow(clippy::identity_op)]
-  pub fn set_title(&mut self, x: &FixedString) {
-    self.0[0..0 + 256].copy_from_slice(&x.0)
+  pub fn set_committed_server_seed(&mut self, items: &[u8; 32]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 325, items) };
   }
 
-  pub fn game_id(&'a self) -> flatbuffers::Array<'a, u8, 16> {
+  pub fn server_seed_hash(&'a self) -> flatbuffers::Array<'a, u8, 32> {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 256) }
+    unsafe { flatbuffers::Array::follow(&self.0, 357) }
   }
 
-  pub fn set_game_id(&mut self, items: &[u8; 16]) {
+  pub fn set_server_seed_hash(&mut self, items: &[u8; 32]) {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 256, items) };
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 357, items) };
   }
 
-  pub fn kyoku_id(&self) -> u64 {
-    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+  pub fn is_seed_committed(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[272..].as_ptr(),
+        self.0[389..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_kyoku_id(&mut self, x: u64) {
+  pub fn set_is_seed_committed(&mut self, x: bool) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -2792,17 +4605,59 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[272..].as_mut_ptr(),
-        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+        self.0[389..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
   }
 
+  pub fn client_seeds(&'a self) -> flatbuffers::Array<'a, u64, 4> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 392) }
+  }
+
+  pub fn set_client_seeds(&mut self, items: &[u64; 4]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 392, items) };
+  }
+
+  pub fn shuffle_client_seeds(&'a self) -> flatbuffers::Array<'a, u64, 4> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 424) }
+  }
+
+  pub fn set_shuffle_client_seeds(&mut self, items: &[u64; 4]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 424, items) };
+  }
+
+  pub fn wall_hash(&'a self) -> flatbuffers::Array<'a, u8, 32> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 456) }
+  }
+
+  pub fn set_wall_hash(&mut self, items: &[u8; 32]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 456, items) };
+  }
+
   pub fn players(&'a self) -> flatbuffers::Array<'a, Player, 4> {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid array in this slot
-    unsafe { flatbuffers::Array::follow(&self.0, 280) }
+    unsafe { flatbuffers::Array::follow(&self.0, 488) }
   }
 
   pub fn set_players(&mut self, x: &[Player; 4]) {
@@ -2812,8 +4667,8 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy(
         x.as_ptr() as *const u8,
-        self.0.as_mut_ptr().add(280),
-        2128,
+        self.0.as_mut_ptr().add(488),
+        2368,
       );
     }
   }
@@ -2825,7 +4680,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2408..].as_ptr(),
+        self.0[2856..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2841,7 +4696,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2408..].as_mut_ptr(),
+        self.0[2856..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2854,7 +4709,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2412..].as_ptr(),
+        self.0[2860..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2870,7 +4725,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2412..].as_mut_ptr(),
+        self.0[2860..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2883,7 +4738,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2416..].as_ptr(),
+        self.0[2864..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2899,7 +4754,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2416..].as_mut_ptr(),
+        self.0[2864..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2912,7 +4767,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2420..].as_ptr(),
+        self.0[2868..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2928,7 +4783,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2420..].as_mut_ptr(),
+        self.0[2868..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2941,7 +4796,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2424..].as_ptr(),
+        self.0[2872..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2957,7 +4812,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2424..].as_mut_ptr(),
+        self.0[2872..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2970,7 +4825,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[2428..].as_ptr(),
+        self.0[2876..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -2986,7 +4841,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[2428..].as_mut_ptr(),
+        self.0[2876..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -2996,12 +4851,12 @@ impl<'a> GameState {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid struct in this slot
-    unsafe { &*(self.0[2432..].as_ptr() as *const Taku) }
+    unsafe { &*(self.0[2880..].as_ptr() as *const Taku) }
   }
 
   #[allow(clippy::identity_op)]
   pub fn set_taku(&mut self, x: &Taku) {
-    self.0[2432..2432 + 684].copy_from_slice(&x.0)
+    self.0[2880..2880 + 820].copy_from_slice(&x.0)
   }
 
   pub fn taku_cursol(&self) -> u32 {
@@ -3011,7 +4866,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[3116..].as_ptr(),
+        self.0[3700..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -3027,12 +4882,26 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[3116..].as_mut_ptr(),
+        self.0[3700..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
+  pub fn dice(&'a self) -> flatbuffers::Array<'a, u8, 2> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 3704) }
+  }
+
+  pub fn set_dice(&mut self, items: &[u8; 2]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 3704, items) };
+  }
+
   pub fn dora_len(&self) -> u32 {
     let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
     // Safety:
@@ -3040,7 +4909,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[3120..].as_ptr(),
+        self.0[3708..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -3056,7 +4925,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[3120..].as_mut_ptr(),
+        self.0[3708..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -3069,7 +4938,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[3124..].as_ptr(),
+        self.0[3712..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -3085,7 +4954,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[3124..].as_mut_ptr(),
+        self.0[3712..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
@@ -3098,7 +4967,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[3128..].as_ptr(),
+        self.0[3716..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
@@ -3114,7 +4983,7 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[3128..].as_mut_ptr(),
+        self.0[3716..].as_mut_ptr(),
         core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
       );
     }
@@ -3127,7 +4996,7 @@ impl<'a> GameState {
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[3132..].as_ptr(),
+        self.0[3720..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
@@ -3143,22 +5012,179 @@ impl<'a> GameState {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[3132..].as_mut_ptr(),
+        self.0[3720..].as_mut_ptr(),
         core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
       );
     }
   }
 
+  pub fn is_kyoku_end(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3724..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_kyoku_end(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3724..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_renchan(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3725..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_renchan(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3725..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_ryuukyoku(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3726..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_ryuukyoku(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3726..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn is_game_end(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3727..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_game_end(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3727..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
   pub fn rule(&self) -> &Rule {
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid struct in this slot
-    unsafe { &*(self.0[3136..].as_ptr() as *const Rule) }
+    unsafe { &*(self.0[3728..].as_ptr() as *const Rule) }
   }
 
   #[allow(clippy::identity_op)]
   pub fn set_rule(&mut self, x: &Rule) {
-    self.0[3136..3136 + 60].copy_from_slice(&x.0)
+    self.0[3728..3728 + 60].copy_from_slice(&x.0)
+  }
+
+  pub fn is_chankan(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3788..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_is_chankan(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3788..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn chankan_pai(&self) -> &Pai {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[3789..].as_ptr() as *const Pai) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_chankan_pai(&mut self, x: &Pai) {
+    self.0[3789..3789 + 6].copy_from_slice(&x.0)
   }
 
   pub fn unpack(&self) -> GameStateT {
@@ -3166,6 +5192,16 @@ impl<'a> GameState {
       title: self.title().unpack(),
       game_id: self.game_id().into(),
       kyoku_id: self.kyoku_id(),
+      seed: self.seed(),
+      shuffle_count: self.shuffle_count(),
+      is_fair_shuffle: self.is_fair_shuffle(),
+      server_seed: self.server_seed().into(),
+      committed_server_seed: self.committed_server_seed().into(),
+      server_seed_hash: self.server_seed_hash().into(),
+      is_seed_committed: self.is_seed_committed(),
+      client_seeds: self.client_seeds().into(),
+      shuffle_client_seeds: self.shuffle_client_seeds().into(),
+      wall_hash: self.wall_hash().into(),
       players: { let players = self.players(); flatbuffers::array_init(|i| players.get(i).unpack()) },
       player_len: self.player_len(),
       bakaze: self.bakaze(),
@@ -3175,11 +5211,18 @@ impl<'a> GameState {
       teban: self.teban(),
       taku: self.taku().unpack(),
       taku_cursol: self.taku_cursol(),
+      dice: self.dice().into(),
       dora_len: self.dora_len(),
       uradora_len: self.uradora_len(),
       is_non_duplicate: self.is_non_duplicate(),
       seq: self.seq(),
+      is_kyoku_end: self.is_kyoku_end(),
+      is_renchan: self.is_renchan(),
+      is_ryuukyoku: self.is_ryuukyoku(),
+      is_game_end: self.is_game_end(),
       rule: self.rule().unpack(),
+      is_chankan: self.is_chankan(),
+      chankan_pai: self.chankan_pai().unpack(),
     }
   }
 }
@@ -3189,6 +5232,16 @@ pub struct GameStateT {
   pub title: FixedStringT,
   pub game_id: [u8; 16],
   pub kyoku_id: u64,
+  pub seed: u64,
+  pub shuffle_count: u32,
+  pub is_fair_shuffle: bool,
+  pub server_seed: [u8; 32],
+  pub committed_server_seed: [u8; 32],
+  pub server_seed_hash: [u8; 32],
+  pub is_seed_committed: bool,
+  pub client_seeds: [u64; 4],
+  pub shuffle_client_seeds: [u64; 4],
+  pub wall_hash: [u8; 32],
   pub players: [PlayerT; 4],
   pub player_len: u32,
   pub bakaze: u32,
@@ -3198,11 +5251,18 @@ pub struct GameStateT {
   pub teban: u32,
   pub taku: TakuT,
   pub taku_cursol: u32,
+  pub dice: [u8; 2],
   pub dora_len: u32,
   pub uradora_len: u32,
   pub is_non_duplicate: bool,
   pub seq: u32,
+  pub is_kyoku_end: bool,
+  pub is_renchan: bool,
+  pub is_ryuukyoku: bool,
+  pub is_game_end: bool,
   pub rule: RuleT,
+  pub is_chankan: bool,
+  pub chankan_pai: PaiT,
 }
 impl GameStateT {
   pub fn pack(&self) -> GameState {
@@ -3210,6 +5270,16 @@ impl GameStateT {
       &self.title.pack(),
       &self.game_id,
       self.kyoku_id,
+      self.seed,
+      self.shuffle_count,
+      self.is_fair_shuffle,
+      &self.server_seed,
+      &self.committed_server_seed,
+      &self.server_seed_hash,
+      self.is_seed_committed,
+      &self.client_seeds,
+      &self.shuffle_client_seeds,
+      &self.wall_hash,
       &flatbuffers::array_init(|i| self.players[i].pack()),
       self.player_len,
       self.bakaze,
@@ -3219,11 +5289,18 @@ impl GameStateT {
       self.teban,
       &self.taku.pack(),
       self.taku_cursol,
+      &self.dice,
       self.dora_len,
       self.uradora_len,
       self.is_non_duplicate,
       self.seq,
+      self.is_kyoku_end,
+      self.is_renchan,
+      self.is_ryuukyoku,
+      self.is_game_end,
       &self.rule.pack(),
+      self.is_chankan,
+      &self.chankan_pai.pack(),
     )
   }
 }
@@ -0,0 +1,181 @@
+use crate::{agari::Agari, mahjong_generated::open_mahjong::GameStateT};
+
+/// 100点単位に切り上げる
+fn ceil100(score: i32) -> i32 {
+    (score + 99) / 100 * 100
+}
+
+/// 和了の基本点を返す
+pub fn get_base_point(agari: &Agari, kiriage: bool) -> i32 {
+    let yakumans: i32 = agari.yaku.iter().filter(|x| x.1 < 0).map(|x| -x.1).sum();
+
+    if yakumans > 0 {
+        return 8000 * yakumans;
+    }
+
+    let han = agari.han;
+
+    if han >= 13 {
+        8000
+    } else if han >= 11 {
+        6000
+    } else if han >= 8 {
+        4000
+    } else if han >= 6 {
+        3000
+    } else if han >= 5 {
+        2000
+    } else {
+        let base = agari.fu << (2 + han);
+
+        // 切り上げ満貫 (30符4翻・60符3翻)
+        if base >= 2000 || (kiriage && base == 1920) {
+            2000
+        } else {
+            base
+        }
+    }
+}
+
+/// 責任払いの対象になる役満
+const PAO_YAKU: [&str; 2] = ["大三元", "大四喜"];
+
+/// 基本点から和了点の支払いを計算する
+fn get_payment(state: &GameStateT, who: usize, by: usize, base: i32) -> [i32; 4] {
+    let oya = state.oya as usize;
+    let mut score_diff = [0; 4];
+
+    if who != by {
+        let score = ceil100(base * if who == oya { 6 } else { 4 });
+        score_diff[who] += score;
+        score_diff[by] -= score;
+    } else {
+        // 三麻の北家折半では、いない北家の分を残りの2人で半分ずつ払う
+        let absent = match state.player_len == 3 && state.rule.sanma_tsumo_type == 1 {
+            true if who == oya => 2,
+            true => 1,
+            false => 0,
+        };
+
+        for i in (0..state.player_len as usize).filter(|i| *i != who) {
+            // 親は子の倍払う
+            let share = if who == oya || i == oya { 2 } else { 1 };
+            let score = ceil100((base * (share * 2 + absent) + 1) / 2);
+            score_diff[who] += score;
+            score_diff[i] -= score;
+        }
+    }
+
+    score_diff
+}
+
+/// 責任払いを考慮した和了点の支払い
+/// 責任払いの役満分は、ツモなら責任者が全額、ロンなら放銃者と折半する
+fn get_yaku_payment(state: &GameStateT, who: usize, by: usize, agari: &Agari) -> [i32; 4] {
+    let kiriage = state.rule.enable_kiriage;
+
+    let pao = match state.get_pao_player(who, by, agari) {
+        Some(x) => x,
+        None => return get_payment(state, who, by, get_base_point(agari, kiriage)),
+    };
+
+    let (pao_yaku, rest_yaku): (Vec<_>, Vec<_>) = agari
+        .yaku
+        .iter()
+        .cloned()
+        .partition(|(name, _)| PAO_YAKU.contains(&name.as_str()));
+    let mut score_diff = match rest_yaku.is_empty() {
+        true => [0; 4],
+        false => {
+            let rest = Agari {
+                yaku: rest_yaku,
+                ..Default::default()
+            };
+            get_payment(state, who, by, get_base_point(&rest, kiriage))
+        }
+    };
+
+    let yakumans: i32 = pao_yaku.iter().map(|x| -x.1).sum();
+    let score = 8000 * yakumans * if who == state.oya as usize { 6 } else { 4 };
+    score_diff[who] += score;
+    if who == by {
+        score_diff[pao] -= score;
+    } else {
+        score_diff[pao] -= score / 2;
+        score_diff[by] -= score / 2;
+    }
+
+    score_diff
+}
+
+pub trait PaymentBehavior {
+    fn get_pao_player(&self, who: usize, by: usize, agari: &Agari) -> Option<usize>;
+    fn get_agari_payment(&self, who: usize, by: usize, agari: &Agari) -> [i32; 4];
+    fn get_bonus_payment(&self, who: usize, by: usize) -> [i32; 4];
+    fn get_nagashi_payment(&self, who: usize) -> [i32; 4];
+}
+
+impl PaymentBehavior for GameStateT {
+    /// 責任払いになるプレイヤー
+    /// 責任のあるプレイヤー自身が放銃した場合は通常の支払いになる
+    fn get_pao_player(&self, who: usize, by: usize, agari: &Agari) -> Option<usize> {
+        let player = &self.players[who];
+        let pao = player.pao_player as usize;
+
+        if player.is_pao
+            && pao != by
+            && agari
+                .yaku
+                .iter()
+                .any(|(name, _)| PAO_YAKU.contains(&name.as_str()))
+        {
+            Some(pao)
+        } else {
+            None
+        }
+    }
+
+    /// 和了点の支払い
+    /// whoとbyが同じならツモ和了として全員で支払う
+    /// 割れ目のプレイヤーは受け取り・支払いともに倍になる
+    fn get_agari_payment(&self, who: usize, by: usize, agari: &Agari) -> [i32; 4] {
+        let mut score_diff = get_yaku_payment(self, who, by, agari);
+
+        if let Some(wareme) = self.get_wareme_player() {
+            if wareme == who {
+                for diff in score_diff.iter_mut() {
+                    *diff *= 2;
+                }
+            } else {
+                let score = score_diff[wareme];
+                score_diff[wareme] += score;
+                score_diff[who] -= score;
+            }
+        }
+
+        score_diff
+    }
+
+    /// 積み棒(1本場300点)と供託リーチ棒の支払い
+    fn get_bonus_payment(&self, who: usize, by: usize) -> [i32; 4] {
+        let honba = self.tsumobou as i32;
+        let mut score_diff = [0; 4];
+
+        if who != by {
+            score_diff[by] -= 300 * honba;
+        } else {
+            for i in (0..self.player_len as usize).filter(|i| *i != who) {
+                score_diff[i] -= 100 * honba;
+            }
+        }
+
+        score_diff[who] = -score_diff.iter().sum::<i32>() + self.riichibou as i32 * 1000;
+
+        score_diff
+    }
+
+    /// 流し満貫の支払い (満貫のツモ和了と同じ)
+    fn get_nagashi_payment(&self, who: usize) -> [i32; 4] {
+        get_payment(self, who, who, 2000)
+    }
+}
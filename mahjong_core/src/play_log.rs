@@ -60,6 +60,8 @@ pub struct PlayLog {
     agaris_log: AgarisLog,
     nagare_log: NagareLog,
     actions_log: ActionsLog,
+    game_end_log: GameEndLog,
+    wall_hash_log: WallHashLog,
 }
 
 pub struct PaiyamaBatch {
@@ -237,6 +239,7 @@ impl Iterator for PaiyamaBatch {
 pub struct GameLog {
     id_vec: Vec<String>,
     started_at_vec: Vec<u64>,
+    seed_vec: Vec<u64>,
 }
 
 #[derive(Default)]
@@ -313,6 +316,29 @@ pub struct AgarisLog {
     score_diff_vec: Vec<Option<Vec<Option<i32>>>>,
     owari_vec: Vec<bool>,
     nukidora_vec: Vec<u32>,
+    pao_vec: Vec<i32>,
+}
+
+/// 和了ログの1件分
+pub struct AgariLogEntry<'a> {
+    pub kyoku_id: u64,
+    pub machipai: u32,
+    pub score: i32,
+    pub fu: i32,
+    pub han: i32,
+    pub pai_ids: &'a [Option<u32>],
+    pub yaku: &'a [(String, i32)],
+    pub dora: &'a [Option<u32>],
+    pub uradora: &'a [Option<u32>],
+    pub dora_orig: &'a [Option<u32>],
+    pub uradora_orig: &'a [Option<u32>],
+    pub who: i32,
+    pub by: i32,
+    pub score_diff: &'a [Option<i32>],
+    pub owari: bool,
+    pub nukidora: u32,
+    pub pao: i32,
+    pub aka_type: u32,
 }
 
 #[derive(Default)]
@@ -322,6 +348,20 @@ pub struct NagareLog {
     score_diff_vec: Vec<Option<Vec<Option<i32>>>>,
 }
 
+#[derive(Default)]
+pub struct GameEndLog {
+    game_id_vec: Vec<String>,
+    ended_at_vec: Vec<u64>,
+    reason_vec: Vec<String>,
+    score_vec: Vec<Option<Vec<Option<i32>>>>,
+}
+
+#[derive(Default)]
+pub struct WallHashLog {
+    kyoku_id_vec: Vec<u64>,
+    wall_hash_vec: Vec<String>,
+}
+
 #[derive(Default)]
 pub struct ActionsLog {
     kyoku_id_vec: Vec<u64>,
@@ -333,9 +373,10 @@ pub struct ActionsLog {
 }
 
 impl GameLog {
-    pub fn append(&mut self, id: String, started_at: u64) {
+    pub fn append(&mut self, id: String, started_at: u64, seed: u64) {
         self.id_vec.push(id);
         self.started_at_vec.push(started_at);
+        self.seed_vec.push(seed);
     }
 
     pub fn save_to_parquet<P: AsRef<Path>>(self, path: P) -> anyhow::Result<()> {
@@ -350,10 +391,12 @@ impl GameLog {
 
         let id_vec = StringArray::from(self.id_vec);
         let started_at_vec = UInt64Array::from(self.started_at_vec);
+        let seed_vec = UInt64Array::from(self.seed_vec);
 
         let batch = RecordBatch::try_from_iter(vec![
             ("id", Arc::new(id_vec) as ArrayRef),
             ("started_at", Arc::new(started_at_vec) as ArrayRef),
+            ("seed", Arc::new(seed_vec) as ArrayRef),
         ])?;
 
         let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
@@ -655,46 +698,32 @@ impl AgarisLog {
             score_diff_vec: Vec::new(),
             owari_vec: Vec::new(),
             nukidora_vec: Vec::new(),
+            pao_vec: Vec::new(),
         }
     }
 
-    pub fn append(
-        &mut self,
-        kyoku_id: u64,
-        machipai: u32,
-        score: i32,
-        fu: i32,
-        han: i32,
-        pai_ids: &[Option<u32>],
-        yaku: &[(String, i32)],
-        dora: &[Option<u32>],
-        uradora: &[Option<u32>],
-        dora_orig: &[Option<u32>],
-        uradora_orig: &[Option<u32>],
-        who: i32,
-        by: i32,
-        score_diff: &[Option<i32>],
-        owari: bool,
-        nukidora: u32,
-    ) {
-        self.kyoku_id_vec.push(kyoku_id);
-        self.machipai_vec.push(machipai);
-        self.score_vec.push(score);
-        self.fu_vec.push(fu);
-        self.han_vec.push(han);
-        self.tehai_vec.push(num_to_hai(pai_ids, 0));
-        self.pai_ids_vec.push(Some(pai_ids.to_vec()));
-        self.dora_vec.push(Some(dora.to_vec()));
-        self.uradora_vec.push(Some(uradora.to_vec()));
-        self.dora_orig_vec.push(Some(dora_orig.to_vec()));
-        self.uradora_orig_vec.push(Some(uradora_orig.to_vec()));
-        self.who_vec.push(who);
-        self.by_vec.push(by);
-        self.score_diff_vec.push(Some(score_diff.to_vec()));
-        self.owari_vec.push(owari);
-        self.nukidora_vec.push(nukidora);
-
-        for (name, han) in yaku {
+    pub fn append(&mut self, entry: &AgariLogEntry) {
+        self.kyoku_id_vec.push(entry.kyoku_id);
+        self.machipai_vec.push(entry.machipai);
+        self.score_vec.push(entry.score);
+        self.fu_vec.push(entry.fu);
+        self.han_vec.push(entry.han);
+        self.tehai_vec
+            .push(num_to_hai(entry.pai_ids, entry.aka_type));
+        self.pai_ids_vec.push(Some(entry.pai_ids.to_vec()));
+        self.dora_vec.push(Some(entry.dora.to_vec()));
+        self.uradora_vec.push(Some(entry.uradora.to_vec()));
+        self.dora_orig_vec.push(Some(entry.dora_orig.to_vec()));
+        self.uradora_orig_vec
+            .push(Some(entry.uradora_orig.to_vec()));
+        self.who_vec.push(entry.who);
+        self.by_vec.push(entry.by);
+        self.score_diff_vec.push(Some(entry.score_diff.to_vec()));
+        self.owari_vec.push(entry.owari);
+        self.nukidora_vec.push(entry.nukidora);
+        self.pao_vec.push(entry.pao);
+
+        for (name, han) in entry.yaku {
             self.yaku_vec_builder
                 .values()
                 .field_builder::<StringBuilder>(0)
@@ -740,6 +769,7 @@ impl AgarisLog {
             FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(self.score_diff_vec, 4);
         let owari_vec = BooleanArray::from(self.owari_vec);
         let nukidora_vec = UInt32Array::from(self.nukidora_vec);
+        let pao_vec = Int32Array::from(self.pao_vec);
 
         let batch = RecordBatch::try_from_iter(vec![
             ("kyoku_id", Arc::new(kyoku_id_vec) as ArrayRef),
@@ -759,6 +789,7 @@ impl AgarisLog {
             ("score_diff", Arc::new(score_diff_vec) as ArrayRef),
             ("owari", Arc::new(owari_vec) as ArrayRef),
             ("nukidora", Arc::new(nukidora_vec) as ArrayRef),
+            ("pao", Arc::new(pao_vec) as ArrayRef),
         ])?;
 
         let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
@@ -877,6 +908,56 @@ impl ActionsLog {
     }
 }
 
+impl GameEndLog {
+    pub fn append(
+        &mut self,
+        game_id: String,
+        ended_at: u64,
+        reason: String,
+        score: &[Option<i32>],
+    ) {
+        self.game_id_vec.push(game_id);
+        self.ended_at_vec.push(ended_at);
+        self.reason_vec.push(reason);
+        self.score_vec.push(Some(score.to_vec()));
+    }
+
+    pub fn save_to_parquet<P: AsRef<Path>>(self, path: P) -> anyhow::Result<()> {
+        let file = File::create(path.as_ref())
+            .map_err(|e| anyhow!("Failed to create file at {:?}: {}", path.as_ref(), e))?;
+
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_encoding(Encoding::PLAIN)
+            .set_compression(Compression::GZIP(GzipLevel::default()))
+            .build();
+
+        let game_id_vec = StringArray::from(self.game_id_vec);
+        let ended_at_vec = UInt64Array::from(self.ended_at_vec);
+        let reason_vec = StringArray::from(self.reason_vec);
+        let score_vec =
+            FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(self.score_vec, 4);
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("game_id", Arc::new(game_id_vec) as ArrayRef),
+            ("ended_at", Arc::new(ended_at_vec) as ArrayRef),
+            ("reason", Arc::new(reason_vec) as ArrayRef),
+            ("score", Arc::new(score_vec) as ArrayRef),
+        ])?;
+
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer
+            .write(&batch)
+            .map_err(|e| anyhow!("Failed to write batch to file: {}", e))?;
+
+        writer
+            .close()
+            .map_err(|e| anyhow!("Failed to close writer: {}", e))?;
+
+        Ok(())
+    }
+}
+
 impl NagareLog {
     pub fn append(&mut self, kyoku_id: u64, name: String, score_diff: &[Option<i32>]) {
         self.kyoku_id_vec.push(kyoku_id);
@@ -918,6 +999,44 @@ impl NagareLog {
     }
 }
 
+impl WallHashLog {
+    pub fn append(&mut self, kyoku_id: u64, wall_hash: &[u8; 32]) {
+        self.kyoku_id_vec.push(kyoku_id);
+        self.wall_hash_vec
+            .push(wall_hash.iter().map(|x| format!("{:02x}", x)).collect());
+    }
+
+    pub fn save_to_parquet<P: AsRef<Path>>(self, path: P) -> anyhow::Result<()> {
+        let file = File::create(path.as_ref())
+            .map_err(|e| anyhow!("Failed to create file at {:?}: {}", path.as_ref(), e))?;
+
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_encoding(Encoding::PLAIN)
+            .set_compression(Compression::GZIP(GzipLevel::default()))
+            .build();
+
+        let kyoku_id_vec = UInt64Array::from(self.kyoku_id_vec);
+        let wall_hash_vec = StringArray::from(self.wall_hash_vec);
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("kyoku_id", Arc::new(kyoku_id_vec) as ArrayRef),
+            ("wall_hash", Arc::new(wall_hash_vec) as ArrayRef),
+        ])?;
+
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer
+            .write(&batch)
+            .map_err(|e| anyhow!("Failed to write batch to file: {}", e))?;
+
+        writer
+            .close()
+            .map_err(|e| anyhow!("Failed to close writer: {}", e))?;
+
+        Ok(())
+    }
+}
+
 impl PlayLog {
     pub fn new() -> Self {
         Self {
@@ -929,11 +1048,13 @@ impl PlayLog {
             agaris_log: AgarisLog::new(),
             nagare_log: NagareLog::default(),
             actions_log: ActionsLog::default(),
+            game_end_log: GameEndLog::default(),
+            wall_hash_log: WallHashLog::default(),
         }
     }
 
-    pub fn append_game_log(&mut self, id: String, started_at: u64) {
-        self.game_log.append(id, started_at);
+    pub fn append_game_log(&mut self, id: String, started_at: u64, seed: u64) {
+        self.game_log.append(id, started_at, seed);
     }
 
     pub fn append_game_player_log(&mut self, name: String, game_id: String, player_index: i32) {
@@ -1018,49 +1139,28 @@ impl PlayLog {
         self.haipais_log.append(kyoku_id, player_index, pai_ids);
     }
 
-    pub fn append_agaris_log(
-        &mut self,
-        kyoku_id: u64,
-        machipai: u32,
-        score: i32,
-        fu: i32,
-        han: i32,
-        pai_ids: &[Option<u32>],
-        yaku: &[(String, i32)],
-        dora: &[Option<u32>],
-        uradora: &[Option<u32>],
-        dora_orig: &[Option<u32>],
-        uradora_orig: &[Option<u32>],
-        who: i32,
-        by: i32,
-        score_diff: &[Option<i32>],
-        owari: bool,
-        nukidora: u32,
-    ) {
-        self.agaris_log.append(
-            kyoku_id,
-            machipai,
-            score,
-            fu,
-            han,
-            pai_ids,
-            yaku,
-            dora,
-            uradora,
-            dora_orig,
-            uradora_orig,
-            who,
-            by,
-            score_diff,
-            owari,
-            nukidora,
-        );
+    pub fn append_agaris_log(&mut self, entry: &AgariLogEntry) {
+        self.agaris_log.append(entry);
     }
 
     pub fn append_nagare_log(&mut self, kyoku_id: u64, name: String, score_diff: &[Option<i32>]) {
         self.nagare_log.append(kyoku_id, name, score_diff);
     }
 
+    pub fn append_game_end_log(
+        &mut self,
+        game_id: String,
+        ended_at: u64,
+        reason: String,
+        score: &[Option<i32>],
+    ) {
+        self.game_end_log.append(game_id, ended_at, reason, score);
+    }
+
+    pub fn append_wall_hash_log(&mut self, kyoku_id: u64, wall_hash: &[u8; 32]) {
+        self.wall_hash_log.append(kyoku_id, wall_hash);
+    }
+
     pub fn append_actions_log(
         &mut self,
         kyoku_id: u64,
@@ -1086,6 +1186,8 @@ impl PlayLog {
         fs::create_dir_all(path.as_ref().join(format!("agaris/{}", dtstr)))?;
         fs::create_dir_all(path.as_ref().join(format!("actions/{}", dtstr)))?;
         fs::create_dir_all(path.as_ref().join(format!("nagares/{}", dtstr)))?;
+        fs::create_dir_all(path.as_ref().join(format!("game_ends/{}", dtstr)))?;
+        fs::create_dir_all(path.as_ref().join(format!("wall_hashes/{}", dtstr)))?;
 
         self.game_log.save_to_parquet(path.as_ref().join(format!(
             "games/{}/game-{}.parquet",
@@ -1130,6 +1232,18 @@ impl PlayLog {
             dtstr,
             dt.timestamp()
         )))?;
+        self.game_end_log
+            .save_to_parquet(path.as_ref().join(format!(
+                "game_ends/{}/game_end-{}.parquet",
+                dtstr,
+                dt.timestamp()
+            )))?;
+        self.wall_hash_log
+            .save_to_parquet(path.as_ref().join(format!(
+                "wall_hashes/{}/wall_hash-{}.parquet",
+                dtstr,
+                dt.timestamp()
+            )))?;
         Ok(())
     }
 }
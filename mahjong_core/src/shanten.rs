@@ -358,6 +358,19 @@ impl PaiState {
         }
     }
 
+    pub fn remove(&mut self, hai: &PaiT) {
+        let num = hai.pai_num as usize;
+        if num < 9 {
+            self.hai_count_m[num] -= 1;
+        } else if num < 18 {
+            self.hai_count_p[num - 9] -= 1;
+        } else if num < 27 {
+            self.hai_count_s[num - 18] -= 1;
+        } else {
+            self.hai_count_z[num - 27] -= 1;
+        }
+    }
+
     fn get_shanten_case(&mut self, b_atama: bool, n_fulo: usize) -> i32 {
         let m = mentsu_count(&mut self.hai_count_m, 0);
         let p = mentsu_count(&mut self.hai_count_p, 0);
@@ -421,6 +434,22 @@ impl PaiState {
 
         min_shanten
     }
+
+    /// テンパイ形の手牌に対して、和了となる牌の番号を列挙します
+    pub fn get_machi(&mut self, n_fulo: usize) -> Vec<u8> {
+        (0..34u8)
+            .filter(|num| {
+                let hai = PaiT {
+                    pai_num: *num,
+                    ..Default::default()
+                };
+                self.append(&hai);
+                let is_agari = self.get_shanten(n_fulo) == -1;
+                self.remove(&hai);
+                is_agari
+            })
+            .collect()
+    }
 }
 
 impl PaiT {
@@ -3,7 +3,7 @@
 mod tests {
     use std::path::PathBuf;
 
-    use mahjong_core::{mahjong_generated::open_mahjong::{MentsuFlag, Mentsu, Pai, MentsuType, MentsuPai, GameStateT}, agari::{add_machi_to_mentsu, AgariBehavior}, load_pailist, shanten::{PaiState, all_of_mentsu}};
+    use mahjong_core::{mahjong_generated::open_mahjong::{MentsuFlag, Mentsu, Pai, MentsuType, MentsuPai, GameStateT}, agari::{add_machi_to_mentsu, AgariBehavior, AgariType}, load_pailist, shanten::{PaiState, all_of_mentsu}};
 
     #[test]
     fn test_add_machi_to_mentsu() {
@@ -38,7 +38,7 @@ mod tests {
             ],
         ];
 
-        let p = Pai::new(2, 0, false, false, false);
+        let p = Pai::new(2, 0, false, false, false, false);
 
         let result = add_machi_to_mentsu(&mentsu, &p);
         
@@ -108,7 +108,7 @@ mod tests {
             ],
         ];
 
-        let p = Pai::new(2, 0, false, false, false);
+        let p = Pai::new(2, 0, false, false, false, false);
 
         let result = add_machi_to_mentsu(&mentsu, &p);
         
@@ -179,7 +179,7 @@ mod tests {
         let all_mentsu = all_of_mentsu(&mut pai_state, parquet.fulo.len());
         let all_mentsu_w_machi = add_machi_to_mentsu(&all_mentsu, &parquet.machipai.pack());
 
-        let agari = game_state.get_best_agari(0, &all_mentsu_w_machi, &parquet.fulo, parquet.nukidora as usize).unwrap();
+        let agari = game_state.get_best_agari(0, &all_mentsu_w_machi, &parquet.fulo, parquet.nukidora as usize, 0, AgariType::Tsumo).unwrap();
 
         assert_eq!(agari.fu, parquet.fu);
         assert_eq!(agari.han, parquet.han);
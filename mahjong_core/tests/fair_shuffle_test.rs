@@ -0,0 +1,176 @@
+use std::fs::{self, File};
+
+use arrow_array::StringArray;
+use mahjong_core::{
+    error::GameError,
+    fair_shuffle::{get_server_seed_hash, get_wall_hash, verify},
+    mahjong_generated::open_mahjong::GameStateT,
+    play_log::PlayLog,
+};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use walkdir::WalkDir;
+
+fn create_fair_game(server_seed: &[u8; 32]) -> (GameStateT, PlayLog, [u8; 32], [u8; 32]) {
+    let mut state = GameStateT::default();
+    let mut play_log = PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+    let server_seed_hash = state.commit_server_seed(server_seed);
+    for i in 0..4 {
+        state.set_client_seed(i, 1000 + i as u64).unwrap();
+    }
+    let wall_hash = state.shuffle_fair().unwrap();
+    state.start(&mut play_log);
+
+    (state, play_log, server_seed_hash, wall_hash)
+}
+
+#[test]
+fn fair_shuffle_test() {
+    let server_seed = [7u8; 32];
+    let (mut state, _, server_seed_hash, wall_hash) = create_fair_game(&server_seed);
+
+    // 局の開始時にコミットメントが公開される
+    let view = state.get_player_view(0).unwrap();
+    assert_eq!(view.server_seed_hash, get_server_seed_hash(&server_seed));
+    assert_eq!(view.server_seed_hash, server_seed_hash);
+    assert_eq!(view.wall_hash, wall_hash);
+    assert_eq!(get_wall_hash(&state.taku, &server_seed), wall_hash);
+
+    // 局の途中ではシードを公開しない
+    assert_eq!(state.reveal_shuffle_seed(), Err(GameError::KyokuNotEnded));
+
+    state.is_kyoku_end = true;
+    let seed = state.reveal_shuffle_seed().unwrap();
+
+    assert_eq!(seed.server_seed, server_seed);
+    assert_eq!(seed.client_seeds, vec![1000, 1001, 1002, 1003]);
+    assert_eq!(
+        verify(&seed, &server_seed_hash, &wall_hash),
+        Some(state.taku.clone())
+    );
+}
+
+#[test]
+fn fair_shuffle_tampered_test() {
+    let server_seed = [7u8; 32];
+    let (mut state, _, server_seed_hash, wall_hash) = create_fair_game(&server_seed);
+    state.is_kyoku_end = true;
+
+    // クライアントシードが一つでも違えば検証に失敗する
+    let mut seed = state.reveal_shuffle_seed().unwrap();
+    seed.client_seeds[2] += 1;
+    assert_eq!(verify(&seed, &server_seed_hash, &wall_hash), None);
+
+    // コミットしたものと違うサーバーシードで作った牌山は、牌山のハッシュが合っても検証に失敗する
+    let mut seed = state.reveal_shuffle_seed().unwrap();
+    seed.server_seed = [8u8; 32];
+    let wall_hash = get_wall_hash(&seed.create_taku(), &seed.server_seed);
+    assert_eq!(verify(&seed, &server_seed_hash, &wall_hash), None);
+
+    // 通常のシャッフルではシードを公開できない
+    state.shuffle();
+    assert_eq!(state.reveal_shuffle_seed(), Err(GameError::NoShuffleSeed));
+}
+
+#[test]
+fn fair_shuffle_next_seed_test() {
+    let server_seed = [7u8; 32];
+    let (mut state, _, server_seed_hash, wall_hash) = create_fair_game(&server_seed);
+
+    // 牌山を作った後はクライアントシードを変更できない
+    assert_eq!(
+        state.set_client_seed(0, 9999),
+        Err(GameError::SeedNotCommitted)
+    );
+
+    // 次の局のシードを登録しても、公開するのはこの局の牌山に使ったシード
+    state.commit_server_seed(&[9u8; 32]);
+    state.set_client_seed(0, 9999).unwrap();
+    state.is_kyoku_end = true;
+    let seed = state.reveal_shuffle_seed().unwrap();
+
+    assert_eq!(seed.server_seed, server_seed);
+    assert_eq!(seed.client_seeds, vec![1000, 1001, 1002, 1003]);
+    assert_eq!(
+        verify(&seed, &server_seed_hash, &wall_hash),
+        Some(state.taku.clone())
+    );
+}
+
+#[test]
+fn fair_shuffle_commit_test() {
+    let mut state = GameStateT::default();
+    let mut play_log = PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+
+    // サーバーシードのコミット前はクライアントシードも牌山も受け付けない
+    assert_eq!(
+        state.set_client_seed(0, 1),
+        Err(GameError::SeedNotCommitted)
+    );
+    assert_eq!(state.shuffle_fair(), Err(GameError::SeedNotCommitted));
+
+    state.commit_server_seed(&[7u8; 32]);
+    assert_eq!(state.set_client_seed(0, 1), Ok(()));
+    assert!(state.shuffle_fair().is_ok());
+
+    // コミットは一度の牌山にしか使えない
+    assert_eq!(state.shuffle_fair(), Err(GameError::SeedNotCommitted));
+}
+
+#[test]
+fn fair_shuffle_client_seed_index_test() {
+    let mut state = GameStateT::default();
+    let mut play_log = PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+    assert_eq!(
+        state.set_client_seed(4, 1),
+        Err(GameError::InvalidPlayerIndex)
+    );
+
+    // 三麻では4人目の席にシードを登録できない
+    state.create("test".as_bytes(), 3, &mut play_log);
+    state.commit_server_seed(&[7u8; 32]);
+    assert_eq!(
+        state.set_client_seed(3, 1),
+        Err(GameError::InvalidPlayerIndex)
+    );
+    assert_eq!(state.set_client_seed(2, 1), Ok(()));
+}
+
+#[test]
+fn fair_shuffle_log_test() {
+    let server_seed = [7u8; 32];
+    let (_, play_log, _, wall_hash) = create_fair_game(&server_seed);
+    let dir = std::env::temp_dir().join(format!("fair_shuffle_log_test-{}", std::process::id()));
+
+    play_log.write_to_parquet(&dir).unwrap();
+
+    // 局の開始時に公開したコミットメントが牌譜に残る
+    let path = WalkDir::new(dir.join("wall_hashes"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .find(|e| e.file_type().is_file())
+        .unwrap()
+        .into_path();
+    let batch = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let column = batch
+        .column_by_name("wall_hash")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    let expected: String = wall_hash.iter().map(|x| format!("{:02x}", x)).collect();
+    assert_eq!(column.value(0), expected);
+
+    fs::remove_dir_all(dir).unwrap();
+}
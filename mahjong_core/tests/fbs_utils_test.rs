@@ -1,5 +1,5 @@
 use mahjong_core::{
-    fbs_utils::TakuControl,
+    fbs_utils::{is_aka, TakuControl},
     mahjong_generated::open_mahjong::{TakuT, PaiT},
 };
 
@@ -13,6 +13,7 @@ fn taku_shuffle_test() {
         is_tsumogiri: false,
         is_riichi: false,
         is_nakare: false,
+        is_aka: false,
     };
 
     assert!(
@@ -47,3 +48,39 @@ fn taku_get_range_test() {
     assert_eq!(r3.unwrap().len(), 96);
 }
 
+#[test]
+fn taku_seeded_test() {
+    let taku = TakuT::create_seeded(12345);
+
+    assert_eq!(taku, TakuT::create_seeded(12345), "同じシードで同じ牌山");
+    assert_ne!(taku, TakuT::create_seeded(12346), "違うシードで違う牌山");
+    assert_eq!(taku.length, 136);
+
+    // 空の牌 (PaiT::default()) と区別できる
+    assert!((0..136).all(|i| taku.get(i).unwrap() != PaiT::default()));
+}
+
+#[test]
+fn taku_aka_test() {
+    let mut taku = TakuT::create_seeded(12345);
+
+    // 萬子1枚、筒子2枚、索子0枚
+    taku.set_aka(0b001001);
+
+    let aka = (0..136).map(|i| taku.get(i).unwrap()).filter(|p| p.is_aka).collect::<Vec<_>>();
+
+    assert_eq!(aka.len(), 3);
+    assert_eq!(aka.iter().filter(|p| p.pai_num == 4).count(), 1);
+    assert_eq!(aka.iter().filter(|p| p.pai_num == 13).count(), 2);
+
+    // idは1〜4
+    assert!(!is_aka(13, 0, 0b001001));
+    assert!(is_aka(13, 2, 0b001001));
+    assert!(!is_aka(13, 3, 0b001001));
+    assert!(!is_aka(22, 1, 0b001001));
+
+    let mut pai = aka[0].clone();
+    assert_eq!(format!("{}", pai), if pai.pai_num == 4 { "m0" } else { "p0" });
+    pai.is_aka = false;
+    assert_eq!(format!("{}", pai), if pai.pai_num == 4 { "m5" } else { "p5" });
+}
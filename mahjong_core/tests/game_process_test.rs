@@ -1,7 +1,131 @@
+use std::fs::{self, File};
+
+use arrow_array::StringArray;
 use mahjong_core::{
-    mahjong_generated::open_mahjong::{GameStateT, PaiT},
+    error::GameError,
+    fbs_utils::TakuControl,
+    game_process::LegalAction,
+    mahjong_generated::open_mahjong::{ActionType, GameStateT, MentsuFlag, MentsuType, PaiT},
     play_log,
 };
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use walkdir::WalkDir;
+
+// "123m456p" 形式の文字列を牌番号の列に変換する
+fn parse_pai_nums(s: &str) -> Vec<u8> {
+    let mut nums = Vec::new();
+    let mut pending = Vec::new();
+
+    for c in s.chars() {
+        match c {
+            '1'..='9' => pending.push(c as u8 - b'1'),
+            'm' | 'p' | 's' | 'z' => {
+                let offset = match c {
+                    'm' => 0,
+                    'p' => 9,
+                    's' => 18,
+                    _ => 27,
+                };
+                nums.extend(pending.drain(..).map(|n| n + offset));
+            }
+            _ => {}
+        }
+    }
+
+    nums
+}
+
+// 配牌とツモ順を指定して牌山を作る (is_non_duplicate用)
+// deadには王牌の位置と牌を指定する (0: ドラ表示牌, 10: 嶺上牌)
+fn build_wall(haipai: &[&str], tsumo: &str, dead: &[(usize, &str)], pai_nums: &[u8]) -> Vec<u32> {
+    let mut used = [false; 136];
+    let mut take = |num: u8| -> u32 {
+        let id = (0..4)
+            .map(|i| num as u32 * 4 + i)
+            .find(|id| !used[*id as usize])
+            .expect("同じ牌は4枚までです");
+        used[id as usize] = true;
+        id
+    };
+    let mut wall: Vec<Option<u32>> = vec![None; pai_nums.len() * 4];
+
+    for (idx, h) in haipai.iter().enumerate() {
+        let nums = parse_pai_nums(h);
+        assert_eq!(nums.len(), 13);
+        for (i, num) in nums.into_iter().enumerate() {
+            wall[14 + idx * 13 + i] = Some(take(num));
+        }
+    }
+
+    let tsumo_start = 14 + haipai.len() * 13;
+    for (i, num) in parse_pai_nums(tsumo).into_iter().enumerate() {
+        wall[tsumo_start + i] = Some(take(num));
+    }
+
+    for (index, pai) in dead {
+        wall[*index] = Some(take(parse_pai_nums(pai)[0]));
+    }
+
+    let mut rest = pai_nums
+        .iter()
+        .flat_map(|num| (0..4).map(move |i| *num as u32 * 4 + i))
+        .filter(|id| !used[*id as usize]);
+
+    wall.into_iter()
+        .map(|x| x.unwrap_or_else(|| rest.next().unwrap()))
+        .collect()
+}
+
+fn start_sanma_game(
+    haipai: [&str; 3],
+    tsumo: &str,
+    dead: &[(usize, &str)],
+) -> (GameStateT, play_log::PlayLog) {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+    let pai_nums = (0..34).filter(|n| !(1..8).contains(n)).collect::<Vec<_>>();
+
+    state.create("test".as_bytes(), 3, &mut play_log);
+    state.is_non_duplicate = true;
+    state.load(&build_wall(&haipai, tsumo, dead, &pai_nums));
+    state.start(&mut play_log);
+
+    (state, play_log)
+}
+
+fn start_game(haipai: [&str; 4], tsumo: &str) -> (GameStateT, play_log::PlayLog) {
+    start_game_with_dead(haipai, tsumo, &[])
+}
+
+// 王牌や海底の牌も指定して開始する
+fn start_game_with_dead(
+    haipai: [&str; 4],
+    tsumo: &str,
+    dead: &[(usize, &str)],
+) -> (GameStateT, play_log::PlayLog) {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+    state.is_non_duplicate = true;
+    state.load(&build_wall(
+        &haipai,
+        tsumo,
+        dead,
+        &(0..34).collect::<Vec<_>>(),
+    ));
+    state.start(&mut play_log);
+
+    (state, play_log)
+}
+
+fn find_tehai(state: &GameStateT, who: usize, pai_num: u8) -> usize {
+    let player = state.get_player(who);
+    player.tehai[..player.tehai_len as usize]
+        .iter()
+        .position(|p| p.pai_num == pai_num)
+        .unwrap()
+}
 
 #[test]
 fn game_start_test() {
@@ -27,7 +151,7 @@ fn game_start_test() {
     assert_eq!(player.tehai_len, 13);
     assert_ne!(player.tsumohai, PaiT::default());
 
-    state.sutehai(&mut play_log, 10, false);
+    state.sutehai(&mut play_log, 10, false).unwrap();
     assert!(state.tsumo(&mut play_log).is_ok(), "ツモ失敗");
 
     player = state.get_player(0);
@@ -41,3 +165,2223 @@ fn game_start_test() {
     assert_eq!(player.tehai_len, 13);
     assert_ne!(player.tsumohai, PaiT::default());
 }
+
+#[test]
+fn seeded_game_test() {
+    let create = |seed: u64| {
+        let mut state = GameStateT::default();
+        let mut play_log = play_log::PlayLog::new();
+
+        state.create_with_seed("test".as_bytes(), 4, seed, &mut play_log);
+        let seats = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| {
+                state
+                    .register_player(name.as_bytes(), &mut play_log)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        state.shuffle();
+
+        (state, seats)
+    };
+
+    let (mut state, seats) = create(42);
+    let (mut same, same_seats) = create(42);
+
+    // 同じシードなら席順と牌山が一致する
+    assert_eq!(state.seed, 42);
+    assert_eq!(seats, same_seats);
+    assert_eq!(state.taku, same.taku);
+
+    // 局ごとに違う牌山になるが、シードが同じなら再現される
+    let first = state.taku.clone();
+    state.shuffle();
+    same.shuffle();
+    assert_ne!(state.taku, first);
+    assert_eq!(state.taku, same.taku);
+
+    let (other, _) = create(43);
+    assert_ne!(other.taku, first);
+}
+
+#[test]
+fn seeded_shuffle_golden_test() {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create_with_seed("test".as_bytes(), 4, 42, &mut play_log);
+    state.shuffle();
+
+    // 乱数ライブラリの更新で牌譜の再現性が崩れないよう、牌山の並びを固定値で確かめる
+    let ids = (0..16)
+        .map(|i| state.taku.get(i).unwrap().get_pai_id())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![111, 128, 130, 65, 77, 118, 74, 135, 88, 92, 29, 100, 24, 76, 134, 16]
+    );
+    assert_eq!(state.dice, [2, 3]);
+}
+
+#[test]
+fn chii_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "23m456p789s55667z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "4m",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    // 4mを捨てる
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 下家以外はチーできない
+    let param = find_tehai(&state, 1, 1) as u32 | (find_tehai(&state, 1, 2) as u32) << 8;
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_CHII, 2, param)
+        .is_err());
+
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_CHII, 1, param)
+        .is_ok());
+
+    let player = state.get_player(1);
+    assert_eq!(player.mentsu_len, 1);
+    assert_eq!(player.tehai_len, 11);
+    assert_eq!(player.mentsu[0].mentsu_type, MentsuType::TYPE_SHUNTSU);
+    assert_eq!(player.mentsu[0].pai_list[2].pai_num, 3);
+    assert_eq!(player.mentsu[0].pai_list[2].flag, MentsuFlag::FLAG_KAMICHA);
+    assert!(state.get_player(0).kawahai[0].is_nakare);
+    assert_eq!(state.teban, 1);
+
+    // 鳴いた後はツモせずに捨てる
+    assert!(state.tsumo(&mut play_log).is_err());
+    assert!(state.sutehai(&mut play_log, 0, false).is_ok());
+    assert_eq!(state.get_player(1).tehai_len, 10);
+    assert_eq!(state.get_next_teban(), 2);
+}
+
+#[test]
+fn chii_invalid_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "23m456p789s55667z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "5m",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 23m + 5m は順子にならない
+    let param = find_tehai(&state, 1, 1) as u32 | (find_tehai(&state, 1, 2) as u32) << 8;
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_CHII, 1, param)
+        .is_err());
+    assert_eq!(state.get_player(1).mentsu_len, 0);
+}
+
+#[test]
+fn kuikae_test() {
+    for enable_kuinaoshi in [false, true] {
+        // 2人目 (index 1) が23mで4mをチーする
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "1234m456p789s556z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+            ],
+            "4m",
+        );
+        state.rule.enable_kuinaoshi = enable_kuinaoshi;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        let param = find_tehai(&state, 1, 1) as u32 | (find_tehai(&state, 1, 2) as u32) << 8;
+        state
+            .action(&mut play_log, ActionType::ACTION_CHII, 1, param)
+            .unwrap();
+
+        if enable_kuinaoshi {
+            // 喰い替えありのルールでは鳴いた牌も捨てられる
+            assert!(state.get_kuikae_pai_nums(1).is_empty());
+            let index = find_tehai(&state, 1, 3);
+            assert!(state.sutehai(&mut play_log, index, false).is_ok());
+            continue;
+        }
+
+        // 鳴いた4mと筋の1mは捨てられない
+        assert_eq!(state.get_kuikae_pai_nums(1), vec![3, 0]);
+        for pai_num in [0, 3] {
+            let index = find_tehai(&state, 1, pai_num);
+            assert_eq!(
+                state.sutehai(&mut play_log, index, false),
+                Err(GameError::Kuikae)
+            );
+            assert!(!state
+                .legal_actions(1)
+                .contains(&LegalAction::Sutehai(index)));
+        }
+
+        let index = find_tehai(&state, 1, 31);
+        assert!(state
+            .legal_actions(1)
+            .contains(&LegalAction::Sutehai(index)));
+        assert!(state.sutehai(&mut play_log, index, false).is_ok());
+    }
+}
+
+#[test]
+fn chii_houtei_test() {
+    let (mut state, mut play_log) = start_game_with_dead(
+        [
+            "19m19p19s1234567z",
+            "23m456p789s55667z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "",
+        &[(135, "4m")],
+    );
+    state.taku_cursol = 135;
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 河底牌はチーできない
+    let param = find_tehai(&state, 1, 1) as u32 | (find_tehai(&state, 1, 2) as u32) << 8;
+    assert!(!state.legal_actions(1).contains(&LegalAction::Chii(param)));
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_CHII, 1, param),
+        Err(GameError::WallExhausted)
+    );
+}
+
+#[test]
+fn pon_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "23m456p789s55667z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "6z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    // 6zを捨てる
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 対子がないとポンできない
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_PON, 2, 0x0100)
+        .is_err());
+
+    let index = find_tehai(&state, 1, 32) as u32;
+    assert!(state
+        .action(
+            &mut play_log,
+            ActionType::ACTION_PON,
+            1,
+            index | (index + 1) << 8
+        )
+        .is_ok());
+
+    let player = state.get_player(1);
+    assert_eq!(player.mentsu_len, 1);
+    assert_eq!(player.tehai_len, 11);
+    assert_eq!(player.mentsu[0].mentsu_type, MentsuType::TYPE_KOUTSU);
+    assert!(player.mentsu[0].pai_list[..3]
+        .iter()
+        .any(|p| p.flag == MentsuFlag::FLAG_KAMICHA));
+    assert_eq!(state.teban, 1);
+
+    // 鳴いた後はツモせずに捨てる
+    assert!(state.tsumo(&mut play_log).is_err());
+    assert!(state.sutehai(&mut play_log, 0, false).is_ok());
+    assert_eq!(state.get_next_teban(), 2);
+}
+
+#[test]
+fn pon_skip_turn_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "6z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    state.players[2].is_ippatsu = true;
+
+    let index = find_tehai(&state, 3, 32) as u32;
+    assert!(state
+        .action(
+            &mut play_log,
+            ActionType::ACTION_PON,
+            3,
+            index | (index + 1) << 8
+        )
+        .is_ok());
+
+    // 間のプレイヤーのツモは飛ばされる
+    let player = state.get_player(3);
+    assert_eq!(state.teban, 3);
+    assert!(player.mentsu[0].pai_list[..3]
+        .iter()
+        .any(|p| p.flag == MentsuFlag::FLAG_SIMOCHA));
+    assert!(!state.get_player(2).is_ippatsu);
+
+    assert!(state.sutehai(&mut play_log, 0, false).is_ok());
+    assert_eq!(state.get_next_teban(), 0);
+}
+
+#[test]
+fn pon_houtei_test() {
+    let (mut state, mut play_log) = start_game_with_dead(
+        [
+            "19m19p19s1234567z",
+            "23m456p789s55667z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "",
+        &[(135, "6z")],
+    );
+    state.taku_cursol = 135;
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 河底牌はポンできない
+    let index = find_tehai(&state, 1, 32) as u32;
+    let param = index | (index + 1) << 8;
+    assert!(!state.legal_actions(1).contains(&LegalAction::Pon(param)));
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_PON, 1, param),
+        Err(GameError::WallExhausted)
+    );
+}
+
+#[test]
+fn ankan_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "1111m2345678p19s",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "3z",
+    );
+    let remain = state.remain();
+
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 0, 0)
+        .is_ok());
+
+    let player = state.get_player(0);
+    assert_eq!(player.mentsu_len, 1);
+    assert_eq!(player.mentsu[0].mentsu_type, MentsuType::TYPE_ANKAN);
+    assert_eq!(player.mentsu[0].pai_len, 4);
+    assert_eq!(player.tehai_len, 10);
+    // 嶺上牌をツモしている
+    assert!(player.is_tsumo);
+    assert_eq!(player.tsumohai, state.taku.n1[10]);
+    // 暗槓のドラは即めくる
+    assert_eq!(state.dora_len, 2);
+    assert_eq!(state.remain(), remain - 2);
+
+    assert!(state.sutehai(&mut play_log, 13, false).is_ok());
+    assert_eq!(state.get_next_teban(), 1);
+}
+
+#[test]
+fn minkan_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "1234m5678p1234s1z",
+            "2468m2468p2468s2z",
+            "123m456p789s1777z",
+            "3579m3579p3579s3z",
+        ],
+        "7z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 槓子がないと大明槓できない
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 1, 0)
+        .is_err());
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 2, 0)
+        .is_ok());
+
+    let player = state.get_player(2);
+    assert_eq!(state.teban, 2);
+    assert_eq!(player.mentsu[0].mentsu_type, MentsuType::TYPE_MINKAN);
+    assert!(player.mentsu[0].pai_list[..4]
+        .iter()
+        .any(|p| p.flag == MentsuFlag::FLAG_TOIMEN));
+    assert_eq!(player.tehai_len, 10);
+    assert!(player.is_tsumo);
+    // 明槓のドラは打牌後にめくる
+    assert_eq!(state.dora_len, 1);
+
+    assert!(state.sutehai(&mut play_log, 13, false).is_ok());
+    assert_eq!(state.dora_len, 2);
+    assert_eq!(state.get_next_teban(), 3);
+}
+
+#[test]
+fn kakan_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "1234m5678p1234s1z",
+            "2468m2468p2468s2z",
+            "123m456p789s1277z",
+            "3579m3579p3579s3z",
+        ],
+        "7z5z6z5z7z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    let index = find_tehai(&state, 2, 33) as u32;
+    state
+        .action(
+            &mut play_log,
+            ActionType::ACTION_PON,
+            2,
+            index | (index + 1) << 8,
+        )
+        .unwrap();
+    state.sutehai(&mut play_log, 0, false).unwrap();
+
+    for _ in 0..3 {
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+    }
+
+    assert_eq!(state.get_next_teban(), 2);
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 2, 13)
+        .is_ok());
+
+    let player = state.get_player(2);
+    assert_eq!(player.mentsu_len, 1);
+    assert_eq!(player.mentsu[0].mentsu_type, MentsuType::TYPE_MINKAN);
+    assert_eq!(player.mentsu[0].pai_len, 4);
+    assert_eq!(player.tehai_len, 10);
+    assert!(player.is_tsumo);
+}
+
+#[test]
+fn kan_haitei_test() {
+    // 海底牌をツモした後は暗槓できない
+    let (mut state, mut play_log) = start_game_with_dead(
+        [
+            "1111m2345678p19s",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "",
+        &[],
+    );
+    state.taku_cursol = 135;
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.is_last_pai());
+
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_KAN, 0, 0),
+        Err(GameError::WallExhausted)
+    );
+    assert!(!state.legal_actions(0).contains(&LegalAction::Ankan(0)));
+
+    // 河底牌は大明槓できない
+    let (mut state, mut play_log) = start_game_with_dead(
+        [
+            "1234m5678p1234s1z",
+            "2468m2468p2468s2z",
+            "123m456p789s1777z",
+            "3579m3579p3579s3z",
+        ],
+        "",
+        &[(135, "7z")],
+    );
+    state.taku_cursol = 135;
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    assert!(!state.legal_actions(2).contains(&LegalAction::Minkan));
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_KAN, 2, 0),
+        Err(GameError::WallExhausted)
+    );
+}
+
+#[test]
+fn rinshan_kaihou_test() {
+    let (mut state, mut play_log) = start_game_with_dead(
+        [
+            "111m234p567s78s55z",
+            "2468m2468p2468s3z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "1m",
+        &[(10, "9s")],
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state
+        .action(&mut play_log, ActionType::ACTION_KAN, 0, 13)
+        .unwrap();
+
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.iter().any(|(name, _)| name == "嶺上開花"));
+    assert!(!agari.yaku.iter().any(|(name, _)| name == "海底摸月"));
+}
+
+#[test]
+fn chankan_test() {
+    for is_ron in [true, false] {
+        // 3人目 (index 2) が5mをポンし、加槓する牌を2人目 (index 1) が4m6mで待つ
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "46m234p567s55s789s",
+                "55m123p456p789s12z",
+                "3679m3579p3579s2z",
+            ],
+            "5m3z3z4z5m",
+        );
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        let index = find_tehai(&state, 2, 4) as u32;
+        state
+            .action(
+                &mut play_log,
+                ActionType::ACTION_PON,
+                2,
+                index | (index + 1) << 8,
+            )
+            .unwrap();
+        // 2人目がロンを見送るとポンが成立する
+        state
+            .action(&mut play_log, ActionType::ACTION_SYNC, 1, 0)
+            .unwrap();
+        let index = find_tehai(&state, 2, 27);
+        state.sutehai(&mut play_log, index, false).unwrap();
+
+        tsumogiri(&mut state, &mut play_log, 3);
+        state.tsumo(&mut play_log).unwrap();
+        state
+            .action(&mut play_log, ActionType::ACTION_KAN, 2, 13)
+            .unwrap();
+
+        // 槍槓できるプレイヤーがいるので嶺上牌はまだツモしない
+        assert!(state.is_chankan);
+        assert!(!state.get_player(2).is_tsumo);
+        assert!(state.legal_actions(1).contains(&LegalAction::Ron));
+        assert!(state.legal_actions(3).is_empty());
+
+        if is_ron {
+            let agari = state.ron(&mut play_log, 1).unwrap();
+            assert!(agari.yaku.iter().any(|(name, _)| name == "搶槓"));
+            assert_eq!(state.get_player(1).score, 25000 + agari.score);
+            assert_eq!(state.get_player(2).score, 25000 - agari.score);
+        } else {
+            // 見送ると加槓したプレイヤーが嶺上牌をツモし、見送ったプレイヤーはフリテンになる
+            state
+                .respond(&mut play_log, 1, ActionType::ACTION_SYNC, 0)
+                .unwrap();
+            assert!(!state.is_chankan);
+            assert!(state.get_player(2).is_tsumo);
+            assert!(state.get_player(1).is_doujun_furiten);
+        }
+    }
+}
+
+#[test]
+fn haitei_houtei_test() {
+    let haipai = [
+        "234m456p678s2288m",
+        "19m19p19s1234567z",
+        "2468m2468p2468s1z",
+        "3579m3579p3579s2z",
+    ];
+
+    // 一巡ツモ切りしてから牌山の最後の牌でツモ和了
+    let (mut state, mut play_log) = start_game_with_dead(
+        haipai,
+        "",
+        &[
+            (131, "5s"),
+            (132, "5s"),
+            (133, "5p"),
+            (134, "5p"),
+            (135, "8m"),
+        ],
+    );
+    state.taku_cursol = 131;
+    tsumogiri(&mut state, &mut play_log, 4);
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.is_last_pai());
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.iter().any(|(name, _)| name == "海底摸月"));
+
+    // 牌山の最後の牌を捨ててロン和了
+    let (mut state, mut play_log) = start_game_with_dead(
+        [haipai[1], haipai[0], haipai[2], haipai[3]],
+        "",
+        &[(135, "8m")],
+    );
+    state.taku_cursol = 135;
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    let agari = state.ron(&mut play_log, 1).unwrap();
+    assert!(agari.yaku.iter().any(|(name, _)| name == "河底撈魚"));
+}
+
+#[test]
+fn ankan_in_riichi_test() {
+    // 待ちが変わらない暗槓
+    let (mut state, mut play_log) = start_game(
+        [
+            "111m234p567s789s5z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "1m",
+    );
+    state.players[0].is_riichi = true;
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 0, 13)
+        .is_ok());
+
+    // 待ちが変わる暗槓
+    let (mut state, mut play_log) = start_game(
+        [
+            "1112m345p678s222z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "1m",
+    );
+    state.players[0].is_riichi = true;
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 0, 13)
+        .is_err());
+
+    // リーチ後の暗槓を禁止するルール
+    let (mut state, mut play_log) = start_game(
+        [
+            "111m234p567s789s5z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "23m456p789s55667z",
+        ],
+        "1m",
+    );
+    state.rule.kan_in_riichi_type = 0;
+    state.players[0].is_riichi = true;
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_KAN, 0, 13)
+        .is_err());
+}
+
+#[test]
+fn ron_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "234m456p678s2288m",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "8m",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    // 8mを捨てる
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 和了形でなければロンできない
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_RON, 2, 0),
+        Err(GameError::NotAgari)
+    );
+    // 自分の捨て牌ではロンできない
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_RON, 0, 0),
+        Err(GameError::OwnSutehai)
+    );
+
+    let agari = state.ron(&mut play_log, 1).unwrap();
+
+    // 断幺九 40符 (ドラは牌山次第)
+    assert_eq!(agari.fu, 40);
+    assert!(agari.yaku.iter().any(|(name, _)| name == "断么九"));
+    assert_eq!(state.get_player(1).score, 25000 + agari.score);
+    assert_eq!(state.get_player(0).score, 25000 - agari.score);
+    assert_eq!(state.get_player(2).score, 25000);
+    assert_eq!(state.get_player(3).score, 25000);
+}
+
+#[test]
+fn ron_without_yaku_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "234m456p678s1199m",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "6z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    // 1mを捨てる
+    state.sutehai(&mut play_log, 0, false).unwrap();
+
+    // 役がなければロンできない
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_RON, 1, 0),
+        Err(GameError::NoYaku)
+    );
+    assert_eq!(state.get_player(1).score, 25000);
+    assert_eq!(state.get_player(0).score, 25000);
+}
+
+#[test]
+fn kuitan_test() {
+    for enable_kuitan in [true, false] {
+        // 2人目 (index 1) が5mをポンして6s9s待ちになる
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "234m456p78s2255m1z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+            ],
+            "5m7z7z6s",
+        );
+        state.rule.enable_kuitan = enable_kuitan;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        let index = find_tehai(&state, 1, 4) as u32;
+        state
+            .action(
+                &mut play_log,
+                ActionType::ACTION_PON,
+                1,
+                index | (index + 1) << 8,
+            )
+            .unwrap();
+        let index = find_tehai(&state, 1, 27);
+        state.sutehai(&mut play_log, index, false).unwrap();
+
+        // 3人目, 4人目, 親がツモ切りし、親が6sを捨てる
+        tsumogiri(&mut state, &mut play_log, 3);
+
+        if enable_kuitan {
+            let agari = state.ron(&mut play_log, 1).unwrap();
+            assert!(agari.yaku.iter().any(|(name, _)| name == "断么九"));
+        } else {
+            // 喰いタンなしでは鳴いた断么九は役にならない
+            assert_eq!(
+                state.action(&mut play_log, ActionType::ACTION_RON, 1, 0),
+                Err(GameError::NoYaku)
+            );
+            assert_eq!(state.get_player(1).score, 25000);
+        }
+    }
+}
+
+#[test]
+fn ryanhan_shibari_test() {
+    let haipai = [
+        "19m19p19s1234567z",
+        "234m456p678s2288m",
+        "2468m2468p2468s1z",
+        "3579m3579p3579s2z",
+    ];
+
+    for (tsumobou, expected) in [(4, true), (5, false)] {
+        let (mut state, mut play_log) = start_game(haipai, "8m");
+        state.rule.enable_ryanhan_shibari = true;
+        state.tsumobou = tsumobou;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        // 5本場以上は一飜 (断么九) では和了できない
+        let result = state.action(&mut play_log, ActionType::ACTION_RON, 1, 0);
+        if expected {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(result, Err(GameError::NoYaku));
+        }
+    }
+}
+
+#[test]
+fn pao_test() {
+    for (enable_pao, is_tsumo) in [(true, true), (true, false), (false, false)] {
+        // 2人目 (index 1) が親から5z、3人目から6zと7zをポンして1m4m待ちになる
+        let tsumo = if is_tsumo {
+            "5z6z7z3z3z4z1m"
+        } else {
+            "5z6z7z3z1m"
+        };
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "23m44p888s556677z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+            ],
+            tsumo,
+        );
+        state.rule.enable_pao = enable_pao;
+
+        for pai_num in [31, 32, 33] {
+            state.tsumo(&mut play_log).unwrap();
+            state.sutehai(&mut play_log, 13, false).unwrap();
+
+            let index = find_tehai(&state, 1, pai_num) as u32;
+            state
+                .action(
+                    &mut play_log,
+                    ActionType::ACTION_PON,
+                    1,
+                    index | (index + 1) << 8,
+                )
+                .unwrap();
+            let index = find_tehai(&state, 1, 25);
+            state.sutehai(&mut play_log, index, false).unwrap();
+        }
+
+        // 3つ目の三元牌を鳴かせた3人目が責任払いになる
+        let player = state.get_player(1);
+        assert_eq!(player.is_pao, enable_pao);
+        if enable_pao {
+            assert_eq!(player.pao_player, 2);
+        }
+
+        if is_tsumo {
+            tsumogiri(&mut state, &mut play_log, 3);
+            state.tsumo(&mut play_log).unwrap();
+            let agari = state.tsumo_agari(&mut play_log).unwrap();
+
+            // ツモ和了は責任者が全額払う
+            assert_eq!(agari.yaku, vec![("大三元".to_string(), -1)]);
+            assert_eq!(state.get_player(1).score, 25000 + 32000);
+            assert_eq!(state.get_player(2).score, 25000 - 32000);
+            assert_eq!(state.get_player(0).score, 25000);
+            assert_eq!(state.get_player(3).score, 25000);
+        } else {
+            tsumogiri(&mut state, &mut play_log, 2);
+            state.ron(&mut play_log, 1).unwrap();
+
+            // 放銃者と責任者で折半する
+            let pao_payment = if enable_pao { 16000 } else { 0 };
+            assert_eq!(state.get_player(1).score, 25000 + 32000);
+            assert_eq!(state.get_player(2).score, 25000 - pao_payment);
+            assert_eq!(state.get_player(3).score, 25000 - 32000 + pao_payment);
+        }
+    }
+}
+
+#[test]
+fn atozuke_test() {
+    for enable_atozuke in [true, false] {
+        // 2人目 (index 1) が2sをポンして白と8mのシャンポン待ちになる
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "234m456p22s88m551z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+            ],
+            "2s3z3z5z",
+        );
+        state.rule.enable_atozuke = enable_atozuke;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        let index = find_tehai(&state, 1, 19) as u32;
+        state
+            .action(
+                &mut play_log,
+                ActionType::ACTION_PON,
+                1,
+                index | (index + 1) << 8,
+            )
+            .unwrap();
+        let index = find_tehai(&state, 1, 27);
+        state.sutehai(&mut play_log, index, false).unwrap();
+
+        // 親が白を捨てる
+        tsumogiri(&mut state, &mut play_log, 3);
+
+        // 後付けなしでは8mで役がないので白でも和了できない
+        let result = state.action(&mut play_log, ActionType::ACTION_RON, 1, 0);
+        if enable_atozuke {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(result, Err(GameError::NoYaku));
+        }
+    }
+}
+
+#[test]
+fn wareme_test() {
+    // サイコロの目と割れ目のプレイヤー (親は0)
+    for (dice, wareme) in [([1, 1], 1), ([2, 3], 0), ([1, 2], 2)] {
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "234m456p678s2288m",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+            ],
+            "8m",
+        );
+        state.rule.enable_wareme = true;
+        state.dice = dice;
+        assert_eq!(state.get_wareme_player(), Some(wareme));
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+        let agari = state.ron(&mut play_log, 1).unwrap();
+
+        // 割れ目のプレイヤーが和了・放銃すると倍になる
+        let score = if wareme == 2 {
+            agari.score
+        } else {
+            agari.score * 2
+        };
+        assert_eq!(state.get_player(1).score, 25000 + score);
+        assert_eq!(state.get_player(0).score, 25000 - score);
+        assert_eq!(state.get_player(2).score, 25000);
+    }
+
+    // シャッフルでサイコロを振る
+    let mut state = GameStateT::default();
+    state.create("test".as_bytes(), 4, &mut play_log::PlayLog::new());
+    assert_eq!(state.get_wareme_player(), None);
+    state.shuffle();
+    assert!(state.dice.iter().all(|d| (1..=6).contains(d)));
+}
+
+// 3人目 (index 3) が 2s8s のシャンポン待ちになる配牌
+const FURITEN_HAIPAI: [&str; 4] = [
+    "19m19p19s1234567z",
+    "2468m2468p2468s1z",
+    "3579m3579p3579s2z",
+    "345m456p567s2288s",
+];
+
+// 全員ツモ切りで進める
+fn tsumogiri(state: &mut GameStateT, play_log: &mut play_log::PlayLog, count: usize) {
+    for _ in 0..count {
+        state.tsumo(play_log).unwrap();
+        state.sutehai(play_log, 13, false).unwrap();
+    }
+}
+
+#[test]
+fn furiten_own_discard_test() {
+    let (mut state, mut play_log) = start_game(FURITEN_HAIPAI, "6z7z5z8s2s");
+
+    tsumogiri(&mut state, &mut play_log, 5);
+
+    // 自分で8sを捨てているので2sでもロンできない
+    assert!(state.is_furiten(3));
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_RON, 3, 0),
+        Err(GameError::Furiten)
+    );
+}
+
+#[test]
+fn furiten_doujun_test() {
+    let (mut state, mut play_log) = start_game(FURITEN_HAIPAI, "6z7z8s1z");
+
+    tsumogiri(&mut state, &mut play_log, 1);
+    state.tsumo(&mut play_log).unwrap();
+    // 2sを捨てる
+    let index = find_tehai(&state, 1, 19);
+    state.sutehai(&mut play_log, index, false).unwrap();
+
+    // 2sを見逃し、同巡内の8sではロンできない
+    tsumogiri(&mut state, &mut play_log, 1);
+    assert!(state.get_player(3).is_doujun_furiten);
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_RON, 3, 0),
+        Err(GameError::Furiten)
+    );
+
+    // 自分の打牌で解消する
+    tsumogiri(&mut state, &mut play_log, 1);
+    assert!(!state.get_player(3).is_doujun_furiten);
+    assert!(!state.is_furiten(3));
+}
+
+#[test]
+fn furiten_riichi_test() {
+    let (mut state, mut play_log) = start_game(FURITEN_HAIPAI, "6z7z5z1z8s7z5z6z");
+
+    tsumogiri(&mut state, &mut play_log, 3);
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, true).unwrap();
+
+    // 8sを見逃す
+    tsumogiri(&mut state, &mut play_log, 1);
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.get_player(3).is_riichi_furiten);
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // リーチ後の見逃しは打牌しても解消しない
+    tsumogiri(&mut state, &mut play_log, 2);
+    assert!(state.is_furiten(3));
+}
+
+#[test]
+fn furiten_riichi_rule_test() {
+    for furiten_riichi_type in [0, 1] {
+        let (mut state, mut play_log) = start_game(FURITEN_HAIPAI, "6z7z5z8s");
+        state.rule.furiten_riichi_type = furiten_riichi_type;
+
+        tsumogiri(&mut state, &mut play_log, 3);
+        state.tsumo(&mut play_log).unwrap();
+
+        // 8sを捨ててリーチすると自分の捨て牌にフリテンになる
+        let result = state.sutehai(&mut play_log, 13, true);
+        if furiten_riichi_type == 0 {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(result, Err(GameError::Furiten));
+        }
+    }
+}
+
+#[test]
+fn minus_riichi_test() {
+    for enable_minus_riichi in [true, false] {
+        let (mut state, mut play_log) = start_game(FURITEN_HAIPAI, "6z7z5z1z");
+        state.rule.enable_minus_riichi = enable_minus_riichi;
+        state.players[3].score = 500;
+
+        tsumogiri(&mut state, &mut play_log, 3);
+        state.tsumo(&mut play_log).unwrap();
+
+        // 1000点未満ではマイナスリーチありのルールでしかリーチできない
+        let result = state.sutehai(&mut play_log, 13, true);
+        if enable_minus_riichi {
+            assert!(result.is_ok());
+            assert_eq!(state.get_player(3).score, -500);
+        } else {
+            assert_eq!(result, Err(GameError::NotEnoughScore));
+            assert_eq!(state.get_player(3).score, 500);
+        }
+    }
+}
+
+#[test]
+fn riichi_sutehai_ron_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "123m789p789s1122z",
+            "2468m2468p2468s1z",
+            "234m456p678s555p8p",
+            "3579m3679p3579s2z",
+        ],
+        "8p",
+    );
+
+    // 親が8pでリーチ宣言し、対面がロンする
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, true).unwrap();
+    let agari = state.ron(&mut play_log, 2).unwrap();
+
+    // 宣言牌で放銃したリーチは成立せず、リーチ棒は返される
+    assert_eq!(state.riichibou, 0);
+    assert_eq!(state.get_player(0).score, 25000 - agari.score);
+    assert_eq!(state.get_player(2).score, 25000 + agari.score);
+}
+
+const FIRST_TURN_HAIPAI: [&str; 4] = [
+    "123m456p789s1122z",
+    "2468m2468p2468s1z",
+    "3579m3579p3579s2z",
+    "19m19p19s3456677z",
+];
+
+#[test]
+fn double_riichi_test() {
+    let (mut state, mut play_log) = start_game(FIRST_TURN_HAIPAI, "8m8p8s8m2z");
+
+    // 鳴きの入っていない一巡目のリーチはダブル立直
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.is_first_turn(0));
+    state.sutehai(&mut play_log, 13, true).unwrap();
+    assert!(state.get_player(0).is_double_riichi);
+
+    tsumogiri(&mut state, &mut play_log, 3);
+    state.tsumo(&mut play_log).unwrap();
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.contains(&("ダブル立直".to_string(), 2)));
+    assert!(!agari.yaku.iter().any(|(name, _)| name == "立直"));
+}
+
+#[test]
+fn tenhou_test() {
+    // 親の配牌で和了
+    let (mut state, mut play_log) = start_game(FIRST_TURN_HAIPAI, "2z");
+    state.tsumo(&mut play_log).unwrap();
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.iter().any(|(name, _)| name == "天和"));
+
+    // 子の最初のツモで和了
+    let haipai = FIRST_TURN_HAIPAI;
+    let (mut state, mut play_log) =
+        start_game([haipai[3], haipai[0], haipai[1], haipai[2]], "9m2z");
+    tsumogiri(&mut state, &mut play_log, 1);
+    state.tsumo(&mut play_log).unwrap();
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.iter().any(|(name, _)| name == "地和"));
+}
+
+#[test]
+fn renhou_test() {
+    let haipai = FIRST_TURN_HAIPAI;
+
+    for (renhou_type, expected) in [(0, None), (1, Some(-1)), (2, Some(5))] {
+        let (mut state, mut play_log) =
+            start_game([haipai[3], haipai[0], haipai[1], haipai[2]], "2z");
+        state.rule.renhou_type = renhou_type;
+
+        // 子が最初のツモの前にロン和了
+        tsumogiri(&mut state, &mut play_log, 1);
+        let agari = state.ron(&mut play_log, 1).unwrap();
+        let renhou = agari
+            .yaku
+            .iter()
+            .find(|(name, _)| name == "人和")
+            .map(|(_, han)| *han);
+
+        assert_eq!(renhou, expected);
+    }
+}
+
+#[test]
+fn nagare_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "234m456p567s2299p",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "345m456p567s2288s",
+        ],
+        "",
+    );
+
+    tsumogiri(&mut state, &mut play_log, 69);
+    assert_eq!(
+        state.nagare(&mut play_log),
+        Err(GameError::WallNotExhausted)
+    );
+    tsumogiri(&mut state, &mut play_log, 1);
+
+    assert_eq!(state.remain(), 0);
+    assert_eq!(state.tsumo(&mut play_log), Err(GameError::WallExhausted));
+
+    // シャンポン待ちの2人がテンパイ
+    let score_diff = state.nagare(&mut play_log).unwrap();
+    assert_eq!(score_diff, [1500, -1500, -1500, 1500]);
+    assert_eq!(state.get_player(0).score, 26500);
+    assert_eq!(state.get_player(1).score, 23500);
+}
+
+#[test]
+fn nagare_action_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "234m456p567s2299p",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "345m456p567s2288s",
+        ],
+        "",
+    );
+
+    // ツモ切りと見送りだけで局を進める
+    while !state.is_kyoku_end {
+        let teban = state.teban as usize;
+        if state.get_player(teban).is_tsumo {
+            state
+                .action(&mut play_log, ActionType::ACTION_SUTEHAI, teban, 13)
+                .unwrap();
+        } else {
+            let i = (0..4)
+                .find(|i| {
+                    !state.get_player(*i).is_responded
+                        && state.legal_actions(*i).contains(&LegalAction::Pass)
+                })
+                .unwrap();
+            state
+                .action(&mut play_log, ActionType::ACTION_SYNC, i, 0)
+                .unwrap();
+        }
+    }
+
+    // 最後の捨て牌が見送られると荒牌流局になる
+    assert_eq!(state.remain(), 0);
+    assert!(state.is_ryuukyoku);
+    assert_eq!(state.get_player(0).score, 26500);
+    assert_eq!(state.get_player(1).score, 23500);
+}
+
+#[test]
+fn nagashi_mangan_test() {
+    for (is_nakare, expected) in [
+        (false, [12000, -4000, -4000, -4000]),
+        (true, [1500, -1500, -1500, 1500]),
+    ] {
+        let (mut state, mut play_log) = start_game_with_dead(
+            [
+                "234m456p567s2299p",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+                "345m456p567s2288s",
+            ],
+            "",
+            &[
+                (128, "1m"),
+                (129, "5m"),
+                (130, "4s"),
+                (131, "7m"),
+                (132, "9s"),
+                (133, "5m"),
+                (134, "4s"),
+                (135, "7m"),
+            ],
+        );
+        state.taku_cursol = 128;
+        tsumogiri(&mut state, &mut play_log, 8);
+
+        // 親の捨て牌は1mと9sだけなので流し満貫
+        state.players[0].kawahai[0].is_nakare = is_nakare;
+        assert_eq!(state.is_nagashi_mangan(0), !is_nakare);
+        assert!(!state.is_nagashi_mangan(3));
+
+        let score_diff = state.nagare(&mut play_log).unwrap();
+        assert_eq!(score_diff, expected);
+    }
+}
+
+#[test]
+fn nagare_keiten_test() {
+    for enable_keiten in [true, false] {
+        let (mut state, mut play_log) = start_game(
+            [
+                "3579m3579p3579s2z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+                "1111m234p567s789s",
+            ],
+            "",
+        );
+        state.rule.enable_keiten = enable_keiten;
+
+        tsumogiri(&mut state, &mut play_log, 70);
+
+        // 1mを4枚使った待ちは形式テンパイでのみ認められる
+        let score_diff = state.nagare(&mut play_log).unwrap();
+        if enable_keiten {
+            assert_eq!(score_diff, [-1000, -1000, -1000, 3000]);
+        } else {
+            assert_eq!(score_diff, [0, 0, 0, 0]);
+        }
+    }
+}
+
+#[test]
+fn nagare_no_yaku_test() {
+    // 2人目 (index 1) が5mをポンして6s9s待ちになるが、喰いタンなしでは役がない
+    let (mut state, mut play_log) = start_game(
+        [
+            "11m19p19s1234566z",
+            "234m456p78s2255m1z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "5m",
+    );
+    state.rule.enable_kuitan = false;
+    state.rule.enable_keiten = false;
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    let index = find_tehai(&state, 1, 4) as u32;
+    state
+        .action(
+            &mut play_log,
+            ActionType::ACTION_PON,
+            1,
+            index | (index + 1) << 8,
+        )
+        .unwrap();
+    let index = find_tehai(&state, 1, 27);
+    state.sutehai(&mut play_log, index, false).unwrap();
+
+    while state.remain() > 0 {
+        tsumogiri(&mut state, &mut play_log, 1);
+    }
+
+    // 海底摸月は流局時のテンパイ判定の役にならない
+    assert!(state.is_last_pai());
+    assert!(!state.is_tenpai(1));
+
+    let score_diff = state.nagare(&mut play_log).unwrap();
+    assert_eq!(score_diff, [0, 0, 0, 0]);
+}
+
+#[test]
+fn kyuushu_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "345m456p567s2288s",
+        ],
+        "6z7z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.can_kyuushu(0));
+    // 手番以外は宣言できない
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_NAGASHI, 1, 0),
+        Err(GameError::NotKyuushu)
+    );
+
+    assert!(state
+        .action(&mut play_log, ActionType::ACTION_NAGASHI, 0, 0)
+        .is_ok());
+    assert!(state.is_kyoku_end);
+    assert_eq!(
+        state.sutehai(&mut play_log, 13, false),
+        Err(GameError::KyokuEnded)
+    );
+}
+
+#[test]
+fn kyuushu_invalid_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "2468m2468p2468s1z",
+            "19m19p19s1234567z",
+            "3579m3579p3579s2z",
+            "345m456p567s2288s",
+        ],
+        "6z7z",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    // 么九牌が9種類ない
+    assert!(!state.can_kyuushu(0));
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_NAGASHI, 0, 0),
+        Err(GameError::NotKyuushu)
+    );
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // ツモ前は宣言できない
+    assert!(!state.can_kyuushu(1));
+    state.tsumo(&mut play_log).unwrap();
+    assert!(state.can_kyuushu(1));
+    assert!(!state.is_kyoku_end);
+}
+
+#[test]
+fn suufon_renda_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s1z",
+            "345m456p567s228s1z",
+        ],
+        "",
+    );
+
+    for who in 0..4 {
+        state.tsumo(&mut play_log).unwrap();
+        let index = find_tehai(&state, who, 27);
+        state.sutehai(&mut play_log, index, false).unwrap();
+    }
+
+    // 4人目の東が通った時点で流局する
+    assert!(state.tsumo(&mut play_log).is_ok());
+    assert!(state.is_kyoku_end);
+    assert!(!state.get_player(0).is_tsumo);
+}
+
+#[test]
+fn suucha_riichi_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "234m456p567s2299p",
+            "234m456p567s3388s",
+            "678m345p234s1155z",
+            "678m345p789s6677z",
+        ],
+        "1z5z6z7z",
+    );
+
+    for _ in 0..4 {
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, true).unwrap();
+    }
+
+    assert!(state.tsumo(&mut play_log).is_ok());
+    assert!(state.is_kyoku_end);
+    assert_eq!(state.get_player(0).score, 24000);
+}
+
+#[test]
+fn suukaikan_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "1111m6666z19p19s1z",
+            "2222p8888p12345z",
+            "3579m3579p3579s2z",
+            "2468m3579p2468s7z",
+        ],
+        "3z4z",
+    );
+
+    for (who, kan) in [(0, [0, 32]), (1, [10, 16])] {
+        state.tsumo(&mut play_log).unwrap();
+        for pai_num in kan {
+            let index = find_tehai(&state, who, pai_num) as u32;
+            state
+                .action(&mut play_log, ActionType::ACTION_KAN, who, index)
+                .unwrap();
+        }
+        state.sutehai(&mut play_log, 13, false).unwrap();
+    }
+
+    // 2人で4回槓したので流局する
+    assert_eq!(state.get_kan_count(), 4);
+    assert!(state.tsumo(&mut play_log).is_ok());
+    assert!(state.is_kyoku_end);
+}
+
+#[test]
+fn triple_ron_test() {
+    for double_ron_type in [0, 1, 2] {
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "46m234p456p678s88s",
+                "46m345p567p234s66s",
+                "46m234p567p345s77s",
+            ],
+            "5m",
+        );
+        state.rule.double_ron_type = double_ron_type;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.sutehai(&mut play_log, 13, false).unwrap();
+
+        let agaris = state.multi_ron(&mut play_log, &[3, 2, 1]).unwrap();
+        assert!(state.is_kyoku_end);
+
+        let scores = (0..4)
+            .map(|i| state.get_player(i).score)
+            .collect::<Vec<_>>();
+        match double_ron_type {
+            // 頭ハネで下家のみ和了
+            0 => {
+                assert_eq!(agaris.len(), 1);
+                assert_eq!(scores[1], 25000 + agaris[0].score);
+                assert_eq!(scores[2], 25000);
+            }
+            // 三家和
+            1 => {
+                assert!(agaris.is_empty());
+                assert_eq!(scores, vec![25000; 4]);
+            }
+            _ => {
+                assert_eq!(agaris.len(), 3);
+                assert_eq!(
+                    scores[0],
+                    25000 - agaris.iter().map(|a| a.score).sum::<i32>()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn renchan_test() {
+    for oyanagare_type in [0x0f, 0x00] {
+        let (mut state, mut play_log) = start_game(
+            [
+                "19m19p19s1234567z",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+                "345m456p567s2288s",
+            ],
+            "6z",
+        );
+        state.rule.oyanagare_type = oyanagare_type;
+
+        state.tsumo(&mut play_log).unwrap();
+        state.kyuushu(&mut play_log, 0).unwrap();
+        assert!(state.next_kyoku(&mut play_log).is_ok());
+
+        // 途中流局は本場が積まれ、連荘するかはルール次第
+        assert_eq!(state.tsumobou, 1);
+        assert_eq!(state.oya, if oyanagare_type == 0 { 1 } else { 0 });
+        assert!(!state.is_game_end);
+
+        state.start(&mut play_log);
+        assert_eq!(state.teban, state.oya);
+        assert!(!state.is_kyoku_end);
+    }
+}
+
+#[test]
+fn ko_agari_next_kyoku_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "234m456p678s2288m",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "8m",
+    );
+    state.tsumobou = 1;
+    state.riichibou = 2;
+
+    // 局の途中では進められない
+    assert_eq!(
+        state.next_kyoku(&mut play_log),
+        Err(GameError::KyokuNotEnded)
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    let agari = state.ron(&mut play_log, 1).unwrap();
+
+    // 積み棒と供託は和了者が受け取る
+    assert_eq!(state.riichibou, 0);
+    assert_eq!(state.get_player(1).score, 25000 + agari.score + 300 + 2000);
+    assert_eq!(state.get_player(0).score, 25000 - agari.score - 300);
+
+    state.next_kyoku(&mut play_log).unwrap();
+    assert_eq!(state.oya, 1);
+    assert_eq!(state.bakaze, 0);
+    assert_eq!(state.tsumobou, 0);
+}
+
+// オーラス終了時の状態を作る
+fn all_last_state(scores: [i32; 4]) -> (GameStateT, play_log::PlayLog) {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+    state.bakaze = 1;
+    state.oya = 3;
+    state.is_kyoku_end = true;
+    for (i, score) in scores.iter().enumerate() {
+        state.players[i].score = *score;
+    }
+
+    (state, play_log)
+}
+
+#[test]
+fn game_end_test() {
+    let (mut state, mut play_log) = all_last_state([30000, 20000, 25000, 25000]);
+    state.riichibou = 1;
+
+    assert!(state.is_all_last());
+    state.next_kyoku(&mut play_log).unwrap();
+    assert!(state.is_game_end);
+    assert_eq!(state.next_kyoku(&mut play_log), Err(GameError::GameEnded));
+
+    // 供託はトップが受け取る
+    assert_eq!(state.get_player(0).score, 31000);
+    assert_eq!(state.get_ranking(), vec![0, 2, 3, 1]);
+}
+
+#[test]
+fn game_end_tonpu_test() {
+    let (mut state, mut play_log) = all_last_state([25000; 4]);
+    state.rule.enable_tonpu = true;
+    state.bakaze = 0;
+
+    state.next_kyoku(&mut play_log).unwrap();
+    assert!(state.is_game_end);
+    // 同点は起家に近いほうが上位
+    assert_eq!(state.get_ranking(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn game_end_sanma_test() {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create("test".as_bytes(), 3, &mut play_log);
+    state.bakaze = 1;
+    state.oya = 2;
+    state.is_kyoku_end = true;
+    state.players[0].score = 40000;
+
+    state.next_kyoku(&mut play_log).unwrap();
+    assert!(state.is_game_end);
+
+    // 三麻の終局も4人分の列で牌譜に書き出せる
+    let dir = std::env::temp_dir().join(format!("game_end_sanma_test-{}", std::process::id()));
+    play_log.write_to_parquet(&dir).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn shanyu_test() {
+    let (mut state, mut play_log) = all_last_state([29000, 21000, 25000, 25000]);
+    state.rule.shanyu_score = 30000;
+
+    // 誰も30000点に届かなければ西入
+    state.next_kyoku(&mut play_log).unwrap();
+    assert!(!state.is_game_end);
+    assert_eq!(state.bakaze, 2);
+    assert_eq!(state.oya, 0);
+
+    state.players[0].score = 31000;
+    state.players[1].score = 19000;
+    state.next_kyoku(&mut play_log).unwrap();
+    assert!(state.is_game_end);
+}
+
+#[test]
+fn tobi_test() {
+    for enable_tobi in [true, false] {
+        let (mut state, mut play_log) = all_last_state([50000, -1000, 25000, 26000]);
+        state.rule.enable_tobi = enable_tobi;
+        state.bakaze = 0;
+        state.oya = 0;
+
+        // 飛びなしのルールでは続行する
+        state.next_kyoku(&mut play_log).unwrap();
+        assert_eq!(state.is_game_end, enable_tobi);
+        assert_eq!(state.get_ranking(), vec![0, 3, 2, 1]);
+    }
+}
+
+#[test]
+fn agariyame_test() {
+    for enable_agariyame in [true, false] {
+        let (mut state, mut play_log) = all_last_state([20000, 20000, 20000, 40000]);
+        state.rule.enable_agariyame = enable_agariyame;
+        state.is_renchan = true;
+
+        state.next_kyoku(&mut play_log).unwrap();
+        assert_eq!(state.is_game_end, enable_agariyame);
+        assert_eq!(state.tsumobou, 1);
+        assert_eq!(state.oya, 3);
+    }
+}
+
+#[test]
+fn tsumo_agari_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "234m456p567s2299p",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "19m19p19s1234567z",
+        ],
+        "2p",
+    );
+    state.tsumobou = 1;
+
+    state.tsumo(&mut play_log).unwrap();
+    state
+        .action(&mut play_log, ActionType::ACTION_TSUMO, 0, 0)
+        .unwrap();
+
+    // 親のツモ和了は子が均等に支払う
+    let scores = (0..4)
+        .map(|i| state.get_player(i).score)
+        .collect::<Vec<_>>();
+    let payment = 25000 - scores[1];
+    assert!(payment > 100);
+    assert_eq!(scores[2], scores[1]);
+    assert_eq!(scores[3], scores[1]);
+    assert_eq!(scores[0], 25000 + payment * 3);
+    assert!(state.is_renchan);
+}
+
+#[test]
+fn akadora_test() {
+    for (aka_type, expected) in [(0, None), (0b000100, Some(1)), (0b010101, Some(2))] {
+        let (mut state, mut play_log) = start_game(
+            [
+                "234m456p567s2299p",
+                "2468m2468p2468s1z",
+                "3579m3579p3579s2z",
+                "19m19p19s1234567z",
+            ],
+            "8m8m8p8p2p",
+        );
+        state.rule.aka_type = aka_type;
+
+        // 配牌の5pと5sはid 1なので、赤の枚数が1枚以上なら赤牌になる
+        tsumogiri(&mut state, &mut play_log, 4);
+        state.tsumo(&mut play_log).unwrap();
+        let agari = state.tsumo_agari(&mut play_log).unwrap();
+        let aka = agari
+            .yaku
+            .iter()
+            .find(|(name, _)| name == "赤ドラ")
+            .map(|(_, han)| *han);
+
+        assert_eq!(aka, expected);
+
+        // 牌譜の和了手牌では赤5を0で表す
+        let dir =
+            std::env::temp_dir().join(format!("akadora_test-{}-{}", std::process::id(), aka_type));
+        play_log.write_to_parquet(&dir).unwrap();
+        let path = WalkDir::new(dir.join("agaris"))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .find(|e| e.file_type().is_file())
+            .unwrap()
+            .into_path();
+        let batch = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let tehai = batch
+            .column_by_name("tehai")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(tehai.value(0).contains('0'), expected.is_some());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+#[test]
+fn sanma_wall_test() {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create("test".as_bytes(), 3, &mut play_log);
+    state.shuffle();
+    state.start(&mut play_log);
+
+    // 2m〜8mを除いた108枚
+    assert!(state.is_sanma());
+    assert_eq!(state.taku.length, 108);
+    assert!((0..108).all(|i| !(1..8).contains(&state.taku.get(i).unwrap().pai_num)));
+    assert_eq!(state.remain(), 108 - 14 - 13 * 3);
+}
+
+#[test]
+fn sanma_kita_test() {
+    let (mut state, mut play_log) = start_sanma_game(
+        [
+            "123p456p789p78s99m",
+            "19m19p19s1234567z",
+            "1m9m19p19s1235677z",
+        ],
+        "5s5s5s4z",
+        &[(0, "1m"), (10, "9s")],
+    );
+    state.rule.sanma_tsumo_type = 1;
+
+    tsumogiri(&mut state, &mut play_log, 3);
+    state.tsumo(&mut play_log).unwrap();
+    assert_eq!(state.remain(), 108 - 14 - 13 * 3 - 4);
+
+    // ツモ牌の北を抜くと嶺上牌をツモる
+    assert!(state.legal_actions(0).contains(&LegalAction::Kita(13)));
+    state
+        .action(&mut play_log, ActionType::ACTION_KITA, 0, 13)
+        .unwrap();
+
+    let player = state.get_player(0);
+    assert_eq!(player.nukidora, 1);
+    assert_eq!(player.tsumohai.pai_num, 26);
+    assert_eq!(state.remain(), 108 - 14 - 13 * 3 - 5);
+
+    // ドラ表示牌が1mなら9mがドラ、抜いた北もドラになる
+    let agari = state.tsumo_agari(&mut play_log).unwrap();
+    assert!(agari.yaku.contains(&("ドラ".to_string(), 3)));
+
+    // 北家折半では親の和了をいない北家の分も含めて2人で払う
+    let payment = 25000 - state.get_player(1).score;
+    assert_eq!(state.get_player(2).score, state.get_player(1).score);
+    assert_eq!(state.get_player(0).score, 25000 + payment * 2);
+}
+
+#[test]
+fn sanma_invalid_test() {
+    let (mut state, mut play_log) = start_sanma_game(
+        [
+            "123p456p789p78s99m",
+            "56s19m19p1234567z",
+            "1m9m19p19s1235677z",
+        ],
+        "5z",
+        &[],
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+
+    // 北でない牌は抜けない
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_KITA, 0, 13),
+        Err(GameError::IllegalAction)
+    );
+
+    // 三麻ではチーできない
+    let index = find_tehai(&state, 0, 24);
+    state.sutehai(&mut play_log, index, false).unwrap();
+    let param = find_tehai(&state, 1, 22) as u32 | (find_tehai(&state, 1, 23) as u32) << 8;
+    assert_eq!(
+        state.action(&mut play_log, ActionType::ACTION_CHII, 1, param),
+        Err(GameError::IllegalAction)
+    );
+    assert!(state
+        .legal_actions(1)
+        .iter()
+        .all(|a| !matches!(a, LegalAction::Chii(_))));
+}
+
+#[test]
+fn legal_actions_discard_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+            "345m456p567s2288s",
+        ],
+        "6z7z5z1z",
+    );
+
+    // ツモ前はツモに進むだけ
+    assert_eq!(state.legal_actions(0), vec![LegalAction::Pass]);
+    assert!(state.legal_actions(1).is_empty());
+
+    state.tsumo(&mut play_log).unwrap();
+    let actions = state.legal_actions(0);
+
+    let sutehai = actions
+        .iter()
+        .filter(|a| matches!(a, LegalAction::Sutehai(_)))
+        .count();
+    assert_eq!(sutehai, 14);
+    assert!(actions.contains(&LegalAction::Nagashi));
+    assert!(!actions.contains(&LegalAction::Pass));
+    assert!(!actions.contains(&LegalAction::Tsumo));
+
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    tsumogiri(&mut state, &mut play_log, 2);
+    state.tsumo(&mut play_log).unwrap();
+
+    // テンパイを維持する打牌だけリーチできる
+    let riichi = state
+        .legal_actions(3)
+        .into_iter()
+        .filter(|a| matches!(a, LegalAction::Riichi(_)))
+        .collect::<Vec<_>>();
+    assert_eq!(riichi, vec![LegalAction::Riichi(13)]);
+}
+
+#[test]
+fn legal_actions_call_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "2356m456p789s557z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "4m",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 23m, 35m, 56mの3通りのチー
+    let actions = state.legal_actions(1);
+    let chii = actions
+        .iter()
+        .filter(|a| matches!(a, LegalAction::Chii(_)))
+        .count();
+    assert_eq!(chii, 3);
+    assert_eq!(actions.last(), Some(&LegalAction::Pass));
+    assert!(state.legal_actions(0).is_empty());
+    assert!(state.legal_actions(2).is_empty());
+
+    for action in actions {
+        let (action_type, param) = action.to_action();
+        let mut cloned = state.clone();
+        assert!(cloned.action(&mut play_log, action_type, 1, param).is_ok());
+    }
+}
+
+#[test]
+fn legal_actions_aka_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "455m789p789s1122z",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "3m",
+    );
+    state.rule.aka_type = 0b000001;
+    let index = find_tehai(&state, 1, 4);
+    state.players[1].tehai[index].is_aka = true;
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 赤5mと普通の5mのどちらでチーするかを選べる
+    let chii = state
+        .legal_actions(1)
+        .into_iter()
+        .filter_map(|a| match a {
+            LegalAction::Chii(param) => Some(param),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let tehai = state.get_player(1).tehai;
+    let aka = chii
+        .iter()
+        .map(|param| {
+            [param & 0xff, param >> 8]
+                .iter()
+                .any(|i| tehai[*i as usize].is_aka)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(aka, vec![true, false]);
+}
+
+#[test]
+fn legal_actions_ron_test() {
+    let (mut state, mut play_log) = start_game(
+        [
+            "19m19p19s1234567z",
+            "234m456p678s2288m",
+            "2468m2468p2468s1z",
+            "3579m3579p3579s2z",
+        ],
+        "8m",
+    );
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    let actions = state.legal_actions(1);
+    assert!(actions.contains(&LegalAction::Ron));
+    assert!(!actions.contains(&LegalAction::Minkan));
+    assert!(actions.iter().any(|a| matches!(a, LegalAction::Pon(_))));
+
+    state.ron(&mut play_log, 1).unwrap();
+    assert!(state.legal_actions(1).is_empty());
+}
+
+// 8pを捨てると下家がポン、対面がロンできる配牌
+const RESPONSE_HAIPAI: [&str; 4] = [
+    "19m19p19s1234567z",
+    "19m19p19s88p12345z",
+    "234m456p678s555p8p",
+    "3579m2367p3579s2z",
+];
+
+#[test]
+fn respond_priority_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    assert_eq!(state.teban, 0);
+
+    // 応答できない行動は受け付けない
+    assert_eq!(
+        state.respond(&mut play_log, 3, ActionType::ACTION_TSUMO, 0),
+        Err(GameError::IllegalAction)
+    );
+    assert_eq!(
+        state.respond(&mut play_log, 3, ActionType::ACTION_PON, 0),
+        Err(GameError::InvalidTileIndex)
+    );
+
+    let index = find_tehai(&state, 1, 16) as u32;
+    state
+        .respond(
+            &mut play_log,
+            1,
+            ActionType::ACTION_PON,
+            index | (index + 1) << 8,
+        )
+        .unwrap();
+    assert_eq!(
+        state.respond(&mut play_log, 1, ActionType::ACTION_SYNC, 0),
+        Err(GameError::AlreadyResponded)
+    );
+
+    // 全員の応答が揃うまでは解決しない
+    assert_eq!(state.get_player(1).mentsu_len, 0);
+    assert_eq!(state.teban, 0);
+
+    // ロンはポンより優先される
+    state
+        .respond(&mut play_log, 2, ActionType::ACTION_RON, 0)
+        .unwrap();
+    assert!(state.is_kyoku_end);
+    assert_eq!(state.get_player(1).mentsu_len, 0);
+    assert!(state.get_player(2).score > 25000);
+}
+
+#[test]
+fn action_response_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // actionで先にポンしても、ロンできるプレイヤーの応答を待つ
+    let index = find_tehai(&state, 1, 16) as u32;
+    state
+        .action(
+            &mut play_log,
+            ActionType::ACTION_PON,
+            1,
+            index | (index + 1) << 8,
+        )
+        .unwrap();
+    assert_eq!(state.get_player(1).mentsu_len, 0);
+
+    // 後から宣言したロンが優先される
+    state
+        .action(&mut play_log, ActionType::ACTION_RON, 2, 0)
+        .unwrap();
+    assert!(state.is_kyoku_end);
+    assert_eq!(state.get_player(1).mentsu_len, 0);
+}
+
+#[test]
+fn action_sync_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // 下家が見送っても、ロンできるプレイヤーが応答するまでツモしない
+    state
+        .action(&mut play_log, ActionType::ACTION_SYNC, 1, 0)
+        .unwrap();
+    assert!(!state.get_player(1).is_tsumo);
+
+    state
+        .action(&mut play_log, ActionType::ACTION_SYNC, 2, 0)
+        .unwrap();
+    assert_eq!(state.teban, 1);
+    assert!(state.get_player(1).is_tsumo);
+}
+
+#[test]
+fn respond_pon_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    state
+        .respond(&mut play_log, 2, ActionType::ACTION_SYNC, 0)
+        .unwrap();
+    let index = find_tehai(&state, 1, 16) as u32;
+    state
+        .respond(
+            &mut play_log,
+            1,
+            ActionType::ACTION_PON,
+            index | (index + 1) << 8,
+        )
+        .unwrap();
+
+    assert_eq!(state.get_player(1).mentsu_len, 1);
+    assert_eq!(state.teban, 1);
+}
+
+#[test]
+fn respond_pon_order_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    // legal_actionsの候補と逆順の組み合わせでもポンできる
+    let index = find_tehai(&state, 1, 16) as u32;
+    assert!(!state
+        .legal_actions(1)
+        .contains(&LegalAction::Pon((index + 1) | index << 8)));
+    state
+        .respond(&mut play_log, 2, ActionType::ACTION_SYNC, 0)
+        .unwrap();
+    state
+        .respond(
+            &mut play_log,
+            1,
+            ActionType::ACTION_PON,
+            (index + 1) | index << 8,
+        )
+        .unwrap();
+
+    assert_eq!(state.get_player(1).mentsu_len, 1);
+}
+
+#[test]
+fn respond_pass_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+
+    state
+        .respond(&mut play_log, 1, ActionType::ACTION_SYNC, 0)
+        .unwrap();
+    state
+        .respond(&mut play_log, 2, ActionType::ACTION_SYNC, 0)
+        .unwrap();
+
+    // 全員見送りで下家がツモする
+    assert_eq!(state.teban, 1);
+    assert!(state.get_player(1).is_tsumo);
+    assert!(state.get_player(2).is_doujun_furiten);
+}
+
+#[test]
+fn player_view_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    state.tsumo(&mut play_log).unwrap();
+    let view = state.get_player_view(1).unwrap();
+
+    // 自分の手牌は見える
+    assert_eq!(view.player_index, 1);
+    assert_eq!(view.tehai, state.get_player(1).tehai);
+    assert!(!view.is_tsumo);
+
+    // 他家の手牌の枚数とツモ状態だけが見える
+    assert_eq!(view.players[0].tehai_len, 13);
+    assert!(view.players[0].is_tsumo);
+    assert_eq!(view.tsumohai, PaiT::default());
+
+    assert_eq!(view.remain, state.remain());
+    assert_eq!(view.dora_len, 1);
+    assert_eq!(view.dora[0], state.get_dora()[0]);
+    assert_eq!(view.dora[1], PaiT::default());
+
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    let view = state.get_player_view(2).unwrap();
+    assert_eq!(view.players[0].kawahai_len, 1);
+    assert_eq!(view.players[0].kawahai[0].pai_num, 16);
+}
+
+#[test]
+fn invalid_player_index_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "8p");
+
+    // 席の範囲外はパニックせずにエラーを返す
+    for action_type in [
+        ActionType::ACTION_KAN,
+        ActionType::ACTION_NAGASHI,
+        ActionType::ACTION_PON,
+    ] {
+        assert_eq!(
+            state.action(&mut play_log, action_type, 7, 0),
+            Err(GameError::InvalidPlayerIndex)
+        );
+    }
+    assert_eq!(state.get_player_view(7), Err(GameError::InvalidPlayerIndex));
+
+    state.tsumo(&mut play_log).unwrap();
+    state.sutehai(&mut play_log, 13, false).unwrap();
+    assert_eq!(
+        state.respond(&mut play_log, 7, ActionType::ACTION_SYNC, 0),
+        Err(GameError::InvalidPlayerIndex)
+    );
+}
+
+#[test]
+fn error_code_test() {
+    let (mut state, mut play_log) = start_game(RESPONSE_HAIPAI, "");
+
+    // 手番以外の打牌
+    let err = state
+        .action(&mut play_log, ActionType::ACTION_SUTEHAI, 1, 0)
+        .unwrap_err();
+    assert_eq!(err, GameError::NotYourTurn);
+    assert_eq!(err.code(), 1);
+
+    // 未対応の行動
+    let err = state
+        .action(&mut play_log, ActionType(99), 0, 0)
+        .unwrap_err();
+    assert_eq!(err, GameError::UnknownAction);
+    assert_eq!(err.to_string(), "未対応の行動です");
+}
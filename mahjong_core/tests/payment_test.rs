@@ -0,0 +1,87 @@
+use mahjong_core::{
+    agari::Agari,
+    mahjong_generated::open_mahjong::GameStateT,
+    payment::{get_base_point, PaymentBehavior},
+    play_log,
+};
+
+fn create_state() -> GameStateT {
+    let mut state = GameStateT::default();
+    let mut play_log = play_log::PlayLog::new();
+
+    state.create("test".as_bytes(), 4, &mut play_log);
+
+    state
+}
+
+fn agari(fu: i32, han: i32) -> Agari {
+    Agari {
+        fu,
+        han,
+        yaku: vec![("立直".to_string(), han)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn base_point_test() {
+    assert_eq!(get_base_point(&agari(30, 1), false), 240);
+    assert_eq!(get_base_point(&agari(40, 4), false), 2000);
+    assert_eq!(get_base_point(&agari(30, 4), false), 1920);
+    // 切り上げ満貫
+    assert_eq!(get_base_point(&agari(30, 4), true), 2000);
+    assert_eq!(get_base_point(&agari(30, 6), false), 3000);
+
+    let yakuman = Agari {
+        yaku: vec![("国士無双".to_string(), -1), ("四暗刻".to_string(), -1)],
+        ..Default::default()
+    };
+    assert_eq!(get_base_point(&yakuman, false), 16000);
+}
+
+#[test]
+fn ron_payment_test() {
+    let state = create_state();
+
+    // 子 30符1翻 1000点
+    assert_eq!(
+        state.get_agari_payment(1, 2, &agari(30, 1)),
+        [0, 1000, -1000, 0]
+    );
+    // 親 30符2翻 2900点
+    assert_eq!(
+        state.get_agari_payment(0, 3, &agari(30, 2)),
+        [2900, 0, 0, -2900]
+    );
+}
+
+#[test]
+fn tsumo_payment_test() {
+    let state = create_state();
+
+    // 子 30符2翻 500/1000
+    assert_eq!(
+        state.get_agari_payment(2, 2, &agari(30, 2)),
+        [-1000, -500, 2000, -500]
+    );
+    // 親 40符2翻 1300オール
+    assert_eq!(
+        state.get_agari_payment(0, 0, &agari(40, 2)),
+        [3900, -1300, -1300, -1300]
+    );
+    // 子 満貫 2000/4000
+    assert_eq!(
+        state.get_agari_payment(1, 1, &agari(30, 5)),
+        [-4000, 8000, -2000, -2000]
+    );
+}
+
+#[test]
+fn bonus_payment_test() {
+    let mut state = create_state();
+    state.tsumobou = 2;
+    state.riichibou = 1;
+
+    assert_eq!(state.get_bonus_payment(1, 3), [0, 1600, 0, -600]);
+    assert_eq!(state.get_bonus_payment(1, 1), [-200, 1600, -200, -200]);
+}